@@ -10,7 +10,11 @@ use anchor_lang::{AccountDeserialize, Discriminator, __private::base64};
 use dashmap::{mapref::entry::Entry, DashMap};
 use futures::future::join_all;
 use futures_util::StreamExt;
-use merkle_distributor::state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor};
+use jito_merkle_tree::utils::{get_claim_status_pda, get_distributor_metadata_pda};
+use merkle_distributor::state::{
+    claim_status::ClaimStatus, distributor_metadata::DistributorMetadata,
+    merkle_distributor::MerkleDistributor,
+};
 use solana_account_decoder::{UiAccountData, UiAccountEncoding};
 use solana_program::pubkey::Pubkey;
 use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
@@ -47,10 +51,12 @@ impl DerefMut for Cache {
 
 #[derive(Clone)]
 pub struct Cache {
-    /// map from claimant to ClaimStatus account;
+    /// map from ClaimStatus pubkey to ClaimStatus account, a claimant has one per distributor they claimed from;
     claim_status_cache: Arc<DashMap<String, DataAndSlot<ClaimStatus>>>,
     /// map from pubkey to MerkleDistributor account;
     distributor_cache: Arc<DashMap<String, MerkleDistributor>>,
+    /// map from distributor pubkey to its DistributorMetadata account, if the admin set one;
+    metadata_cache: Arc<DashMap<String, DistributorMetadata>>,
     // rpc_client: RpcClient,
     // pubsub_client: PubsubClient,
     program_id: Pubkey,
@@ -63,6 +69,8 @@ pub struct Cache {
     pub default_start_ts: i64,
     pub default_end_ts: i64,
     pub default_mint: String,
+    /// start claim pct scaled by PCT_PRECISION
+    pub default_start_claim_pct: u128,
 }
 
 impl Cache {
@@ -73,10 +81,12 @@ impl Cache {
         default_start_ts: i64,
         default_end_ts: i64,
         default_mint: String,
+        default_start_claim_pct: u128,
     ) -> Self {
         Self {
             claim_status_cache: Arc::new(DashMap::new()),
             distributor_cache: Arc::new(DashMap::new()),
+            metadata_cache: Arc::new(DashMap::new()),
             program_id,
             subscribed: false,
             // unsubscriber: None,
@@ -85,12 +95,18 @@ impl Cache {
             default_start_ts,
             default_end_ts,
             default_mint,
+            default_start_claim_pct,
         }
     }
 
-    pub fn get_claim_status(&self, claimant: &String) -> Option<DataAndSlot<ClaimStatus>> {
+    pub fn get_claim_status(
+        &self,
+        claimant: &Pubkey,
+        distributor: &Pubkey,
+    ) -> Option<DataAndSlot<ClaimStatus>> {
+        let (claim_status, _bump) = get_claim_status_pda(&self.program_id, claimant, distributor);
         self.claim_status_cache
-            .get(claimant)
+            .get(&claim_status.to_string())
             .map(|r| r.value().clone())
     }
 
@@ -107,6 +123,12 @@ impl Cache {
             .map(|r| r.value().clone())
     }
 
+    pub fn get_distributor_metadata(&self, distributor: &String) -> Option<DistributorMetadata> {
+        self.metadata_cache
+            .get(distributor)
+            .map(|r| r.value().clone())
+    }
+
     pub fn _len(&self) -> usize {
         self.claim_status_cache.len()
     }
@@ -155,7 +177,7 @@ impl Cache {
                                                     continue;
                                                 }
                                             };
-                                            let data = match ClaimStatus::try_deserialize(&mut decoded_data.as_slice()).map_err(|err| ApiError::InternalError(Box::new(err))) {
+                                            let data = match ClaimStatus::try_deserialize_any_layout(&decoded_data).map_err(|err| ApiError::InternalError(Box::new(err))) {
                                                 Ok(data) => data,
                                                 Err(_) => {
                                                     println!("Failed to deserialize ClaimStatus");
@@ -235,7 +257,7 @@ impl Cache {
                                 let update_tx_clone = update_tx_clone.clone();
                                 tokio::spawn(async move {
                                     let data =
-                                        ClaimStatus::try_deserialize(&mut account.data.as_slice())
+                                        ClaimStatus::try_deserialize_any_layout(&account.data)
                                             .map_err(|err| ApiError::InternalError(Box::new(err)))
                                             .unwrap();
                                     update_tx_clone
@@ -309,11 +331,10 @@ impl Cache {
                         let distributor = distributors_to_load.get(index).unwrap();
                         match account {
                             Some(account) => {
-                                let distributor_data = MerkleDistributor::try_deserialize(
-                                    &mut account.data.as_slice(),
-                                )
-                                .map_err(|err| ApiError::InternalError(Box::new(err)))
-                                .unwrap();
+                                let distributor_data =
+                                    MerkleDistributor::try_deserialize_any_layout(&account.data)
+                                        .map_err(|err| ApiError::InternalError(Box::new(err)))
+                                        .unwrap();
                                 distributor_cache.insert(
                                     distributor.distributor_pubkey.clone(),
                                     distributor_data,
@@ -358,6 +379,45 @@ impl Cache {
         }
     }
 
+    async fn hydrate_metadata_cache(self: Arc<Self>, rpc_client: &RpcClient) {
+        let metadata_keys = self
+            .get_distributor_keys()
+            .iter()
+            .map(|distributor| get_distributor_metadata_pda(&self.program_id, distributor).0)
+            .collect::<Vec<Pubkey>>();
+
+        match rpc_client
+            .get_multiple_accounts_with_commitment(&metadata_keys, CommitmentConfig::confirmed())
+            .await
+        {
+            Ok(accounts) => {
+                for (index, account) in accounts.value.into_iter().enumerate() {
+                    let distributor = self.distributors.get(index).unwrap();
+                    // metadata is optional, most distributors don't have any
+                    let account = match account {
+                        Some(account) => account,
+                        None => continue,
+                    };
+                    match DistributorMetadata::try_deserialize(&mut account.data.as_slice()) {
+                        Ok(metadata) => {
+                            self.metadata_cache
+                                .insert(distributor.distributor_pubkey.clone(), metadata);
+                        }
+                        Err(err) => {
+                            println!(
+                                "Error deserializing metadata of distributor {}: {:?}",
+                                distributor.distributor_pubkey, err
+                            );
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                println!("Error in metadata gma: {:?}", e);
+            }
+        }
+    }
+
     /// Starts a tokio task in the background that handles updating the cache. getProgramAccounts
     /// and programSubscribe tasks should send account updates through to update_rx
     fn start_cache_updater(
@@ -367,7 +427,7 @@ impl Cache {
         let cache_clone = Arc::clone(&self.claim_status_cache);
         tokio::spawn(async move {
             while let Some((pubkey, data)) = update_rx.recv().await {
-                match cache_clone.entry(data.data.claimant.to_string()) {
+                match cache_clone.entry(pubkey.clone()) {
                     Entry::Occupied(mut entry) => {
                         if entry.get().data == data.data {
                             // println!("Data is the same as what's in cache");
@@ -440,7 +500,7 @@ impl Cache {
                         ),
                     )),
                     RpcFilterType::Memcmp(Memcmp::new(
-                        73,
+                        ClaimStatus::DISTRIBUTOR_OFFSET,
                         solana_rpc_client_api::filter::MemcmpEncodedBytes::Bytes(
                             distributor.to_bytes().into(),
                         ),
@@ -470,6 +530,7 @@ impl Cache {
                     .clone()
                     .hydrate_distributors_cache(&rpc_client)
                     .await;
+                arc_self.clone().hydrate_metadata_cache(&rpc_client).await;
                 interval.tick().await;
             }
         });
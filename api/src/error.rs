@@ -50,6 +50,18 @@ pub enum ApiError {
 
     #[error("Unauthorized")]
     Unauthorized(),
+
+    #[error("Claim sponsoring is not enabled")]
+    RelayerNotConfigured(),
+
+    #[error("Claim status already exists for user {0}")]
+    ClaimStatusExists(String),
+
+    #[error("Transaction serialization error")]
+    TransactionSerializationError(#[from] bincode::Error),
+
+    #[error("Transaction too large")]
+    TransactionTooLarge(),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -119,6 +131,26 @@ impl IntoResponse for ApiError {
                 error!("Unauthorized");
                 (StatusCode::UNAUTHORIZED, "Unauthorized")
             }
+
+            ApiError::RelayerNotConfigured() => {
+                error!("Claim sponsoring is not enabled");
+                (StatusCode::NOT_FOUND, "Claim sponsoring is not enabled")
+            }
+
+            ApiError::ClaimStatusExists(u) => {
+                error!("Claim status already exists for user {u}");
+                (StatusCode::BAD_REQUEST, "Claim status already exists")
+            }
+
+            ApiError::TransactionSerializationError(e) => {
+                error!("Transaction serialization error: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error")
+            }
+
+            ApiError::TransactionTooLarge() => {
+                error!("Transaction too large");
+                (StatusCode::INTERNAL_SERVER_ERROR, "Transaction too large")
+            }
         };
 
         (
@@ -9,10 +9,15 @@ use std::{
 use clap::Parser;
 use csv::Reader;
 use futures::future::join_all;
-use jito_merkle_tree::{airdrop_merkle_tree::AirdropMerkleTree, utils::get_merkle_distributor_pda};
+use jito_merkle_tree::{
+    airdrop_merkle_tree::AirdropMerkleTree,
+    utils::{get_legacy_merkle_distributor_pda, get_merkle_distributor_pda},
+};
+use merkle_distributor::state::claim_status::PCT_PRECISION;
 use router::RouterState;
 use solana_program::pubkey::Pubkey;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::signature::read_keypair_file;
 use tokio::sync::Mutex;
 use tracing::{info, instrument};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
@@ -46,6 +51,11 @@ pub struct Args {
     #[clap(long, env)]
     program_id: Pubkey,
 
+    /// Admin that created the distributors, part of their address. Leave unset for distributors created before
+    /// it was, whose address only derives from the mint and version
+    #[clap(long, env)]
+    base: Option<Pubkey>,
+
     /// Path to csv file of accounts with vested tokens
     #[clap(long, env)]
     unvested_accounts_path: Option<PathBuf>,
@@ -74,9 +84,14 @@ pub struct Args {
     #[clap(long, env)]
     default_mint: Option<String>,
 
-    /// start_amount pct in whole number (50 for 50%)
-    #[clap(long, env, default_value = "50")]
-    start_amount_pct: String,
+    /// start_amount pct in whole number (50 for 50%) if no distributor is found, defaults to 50
+    /// Also read from the former --start-amount-pct flag and START_AMOUNT_PCT env var
+    #[clap(long, env, alias = "start-amount-pct")]
+    default_start_amount_pct: Option<u128>,
+
+    /// Keypair of the relayer that pays fees and rent for sponsored claims, disabled if not set
+    #[clap(long, env)]
+    relayer_keypair_path: Option<PathBuf>,
 }
 
 /// Converts a ui amount to a token amount (with decimals)
@@ -156,11 +171,19 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                     }
                 };
 
-                let (distributor_pubkey, _bump) = get_merkle_distributor_pda(
-                    &args.program_id,
-                    &args.mint,
-                    single_tree.airdrop_version,
-                );
+                let (distributor_pubkey, _bump) = match args.base {
+                    Some(base) => get_merkle_distributor_pda(
+                        &args.program_id,
+                        &base,
+                        &args.mint,
+                        single_tree.airdrop_version,
+                    ),
+                    None => get_legacy_merkle_distributor_pda(
+                        &args.program_id,
+                        &args.mint,
+                        single_tree.airdrop_version,
+                    ),
+                };
 
                 let mut distributors = distributors_clone.lock().await;
                 distributors.push(SingleDistributor {
@@ -173,7 +196,9 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                     tree_clone
                         .lock()
                         .await
-                        .insert(node.claimant, (distributor_pubkey, node.clone()));
+                        .entry(node.claimant)
+                        .or_insert_with(Vec::new)
+                        .push((distributor_pubkey, node.clone()));
                 }
                 let duration_single_tree = start_single_tree.elapsed();
                 println!(
@@ -237,6 +262,14 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         duration_vested_users
     );
 
+    let default_start_amount_pct = match args.default_start_amount_pct {
+        Some(pct) => pct,
+        None => match std::env::var("START_AMOUNT_PCT") {
+            Ok(pct) => pct.parse()?,
+            Err(_) => 50,
+        },
+    };
+
     let mut cache = Cache::new(
         args.program_id,
         distributors.lock().await.clone(),
@@ -245,13 +278,13 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         args.default_end_ts.unwrap_or(3133657200),
         args.default_mint
             .unwrap_or("11111111111111111111111111111111".to_string()),
+        default_start_amount_pct * PCT_PRECISION / 100,
     );
     cache.subscribe(args.rpc_url, args.ws_url).await?;
 
-    let start_amount_pct: u128 = args
-        .start_amount_pct
-        .parse()
-        .expect("Invalid start_amount_pct");
+    let relayer = args
+        .relayer_keypair_path
+        .map(|path| read_keypair_file(&path).expect("Failed reading relayer keypair file"));
 
     let state = Arc::new(RouterState {
         basic_auth_user: args.basic_auth_user.clone(),
@@ -260,7 +293,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         program_id: args.program_id,
         rpc_client,
         cache,
-        start_amount_pct,
+        relayer,
     });
 
     let app = router::get_routes(state);
@@ -15,12 +15,40 @@ use axum::{
     Json, Router,
 };
 
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas, __private::base64};
 use http::Request;
-use jito_merkle_tree::{airdrop_merkle_tree::UserProof, tree_node::TreeNode};
-use merkle_distributor::state::merkle_distributor::MerkleDistributor;
+use jito_merkle_tree::{
+    airdrop_merkle_tree::UserProof,
+    tree_node::TreeNode,
+    utils::{get_claim_bitmap_pda, get_claim_status_pda, get_event_authority_pda},
+};
+use merkle_distributor::{
+    instructions::BatchClaimArgs,
+    state::{
+        claim_bitmap::ClaimBitmap,
+        claim_status::{ClaimStatus, PCT_PRECISION},
+        distributor_metadata::DistributorMetadata,
+        merkle_distributor::{ClaimMode, ClawbackMode, MerkleDistributor},
+    },
+};
 use serde_derive::{Deserialize, Serialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    packet::PACKET_DATA_SIZE,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
 use tower::{
     buffer::BufferLayer, limit::RateLimitLayer, load_shed::LoadShedLayer, timeout::TimeoutLayer,
     ServiceBuilder,
@@ -36,17 +64,15 @@ use tracing::{error, info, instrument, warn, Span};
 
 use crate::{cache::Cache, error, error::ApiError, Result};
 
-const START_AMOUNT_PCT_PRECISION: u128 = 1_000;
-const START_AMOUNT_PCT_DENOM: u128 = 100 * START_AMOUNT_PCT_PRECISION;
-
 /// Calculate claimable amount for a user who hasn't called new_claim yet
 /// This mirrors the logic from ClaimStatus::update_unlocked_amount_claimed
+/// start_claim_pct is scaled by PCT_PRECISION, as stored on the [MerkleDistributor]
 pub fn calculate_claimable_amount_for_new_user(
     unlocked_amount: u64,
     curr_ts: i64,
     start_ts: i64,
     end_ts: i64,
-    start_amount_pct: u128,
+    start_claim_pct: u128,
 ) -> u64 {
     if curr_ts < start_ts {
         return 0;
@@ -64,10 +90,8 @@ pub fn calculate_claimable_amount_for_new_user(
         return unlocked_amount;
     }
 
-    // Start amount is based on start_amount_pct (typically 50%)
-    let start_amount_pct_num = start_amount_pct * START_AMOUNT_PCT_PRECISION;
-    let start_amount =
-        ((unlocked_amount as u128) * start_amount_pct_num / START_AMOUNT_PCT_DENOM) as u64;
+    // Start amount is based on the distributor's start_claim_pct (typically 50%)
+    let start_amount = ((unlocked_amount as u128) * start_claim_pct / PCT_PRECISION) as u64;
 
     // Calculate bonus amount (linear vesting of the remaining portion)
     let bonus_amount =
@@ -76,15 +100,17 @@ pub fn calculate_claimable_amount_for_new_user(
     start_amount + bonus_amount
 }
 
-/// Calculate claimable amount for locked tokens (linear vesting, no start percentage)
+/// Calculate claimable amount for locked tokens (linear or stepped vesting after the cliff, no start percentage)
 /// This mirrors the logic from ClaimStatus::unlocked_amount
 pub fn calculate_locked_amount_claimable(
     locked_amount: u64,
     curr_ts: i64,
     start_ts: i64,
     end_ts: i64,
+    cliff_ts: i64,
+    unlock_period: i64,
 ) -> u64 {
-    if curr_ts < start_ts {
+    if curr_ts < start_ts || curr_ts < cliff_ts {
         return 0;
     }
 
@@ -92,14 +118,19 @@ pub fn calculate_locked_amount_claimable(
         return locked_amount;
     }
 
-    let time_into_unlock = (curr_ts - start_ts) as u128;
+    let mut time_into_unlock = curr_ts - start_ts;
+    if unlock_period > 0 {
+        // Only whole periods are unlocked
+        time_into_unlock -= time_into_unlock % unlock_period;
+    }
+    let time_into_unlock = time_into_unlock as u128;
     let total_unlock_time = (end_ts - start_ts) as u128;
 
     if total_unlock_time == 0 {
         return locked_amount;
     }
 
-    // Linear vesting of the full locked amount
+    // Vesting of the full locked amount
     (time_into_unlock * locked_amount as u128 / total_unlock_time) as u64
 }
 
@@ -107,10 +138,12 @@ pub struct RouterState {
     pub basic_auth_user: Option<String>,
     pub basic_auth_password: Option<String>,
     pub program_id: Pubkey,
-    pub tree: HashMap<Pubkey, (Pubkey, TreeNode)>,
+    /// Leaves of each user, with the distributor of their tree
+    pub tree: HashMap<Pubkey, Vec<(Pubkey, TreeNode)>>,
     pub rpc_client: RpcClient,
     pub cache: Cache,
-    pub start_amount_pct: u128,
+    /// Pays fees and rent for sponsored claims
+    pub relayer: Option<Keypair>,
 }
 
 impl Debug for RouterState {
@@ -161,6 +194,10 @@ pub fn get_routes(state: Arc<RouterState>) -> Router {
             .route("/user/:user_pubkey", get(get_user_info))
             .route("/claim/:user_pubkey", get(get_claim_status))
             .route("/eligibility/:user_pubkey", get(get_eligibility))
+            .route("/proofs/:user_pubkey", get(get_all_user_info))
+            .route("/claims/:user_pubkey", get(get_claim_statuses))
+            .route("/eligibilities/:user_pubkey", get(get_eligibilities))
+            .route("/claim-tx/:user_pubkey", get(get_sponsored_claim_tx))
             .route_layer(ValidateRequestHeaderLayer::basic(
                 state.basic_auth_user.clone().unwrap().as_str(),
                 state.basic_auth_password.clone().unwrap().as_str(),
@@ -171,45 +208,72 @@ pub fn get_routes(state: Arc<RouterState>) -> Router {
             .route("/distributors", get(get_distributors))
             .route("/user/:user_pubkey", get(get_user_info))
             .route("/claim/:user_pubkey", get(get_claim_status))
-            .route("/eligibility/:user_pubkey", get(get_eligibility));
+            .route("/eligibility/:user_pubkey", get(get_eligibility))
+            .route("/proofs/:user_pubkey", get(get_all_user_info))
+            .route("/claims/:user_pubkey", get(get_claim_statuses))
+            .route("/eligibilities/:user_pubkey", get(get_eligibilities))
+            .route("/claim-tx/:user_pubkey", get(get_sponsored_claim_tx));
     }
 
     router.layer(middleware).layer(cors).with_state(state)
 }
 
-fn get_user_proof(
-    merkle_tree: &HashMap<Pubkey, (Pubkey, TreeNode)>,
+/// Proofs of every leaf of the user, one per tree they are in
+fn get_user_proofs(
+    merkle_tree: &HashMap<Pubkey, Vec<(Pubkey, TreeNode)>>,
     pubkey: String,
-) -> Result<UserProof> {
+) -> Result<Vec<UserProof>> {
     let user_pubkey: Pubkey = Pubkey::from_str(pubkey.as_str())?;
-    let node = merkle_tree
+    let nodes = merkle_tree
         .get(&user_pubkey)
         .ok_or(ApiError::UserNotFound(user_pubkey.to_string()))?;
 
-    let proof = UserProof {
-        merkle_tree: node.0.to_string(),
-        amount: node.1.unlocked_amount(),
-        locked_amount: node.1.locked_amount(),
+    nodes
+        .iter()
+        .map(|(distributor, node)| to_user_proof(&user_pubkey, distributor, node))
+        .collect()
+}
+
+fn to_user_proof(user_pubkey: &Pubkey, distributor: &Pubkey, node: &TreeNode) -> Result<UserProof> {
+    Ok(UserProof {
+        merkle_tree: distributor.to_string(),
+        amount: node.unlocked_amount(),
+        locked_amount: node.locked_amount(),
+        leaf_index: node.leaf_index,
+        start_ts: node.start_ts,
+        end_ts: node.end_ts,
         proof: node
-            .1
             .proof
             .to_owned()
             .ok_or(ApiError::ProofNotFound(user_pubkey.to_string()))?,
-    };
-    Ok(proof)
+    })
 }
 
-/// Retrieve the proof for a given user
+/// Retrieve the proof for a given user, in the first tree they are in
 #[instrument(level = "error")]
 async fn get_user_info(
     State(state): State<Arc<RouterState>>,
     Path(user_pubkey): Path<String>,
 ) -> Result<impl IntoResponse> {
     let merkle_tree = &state.tree;
-    let proof = get_user_proof(merkle_tree, user_pubkey)?;
+    let proof = get_user_proofs(merkle_tree, user_pubkey.clone())?
+        .into_iter()
+        .next()
+        .ok_or(ApiError::UserNotFound(user_pubkey))?;
     Ok(Json(proof))
 }
 
+/// Retrieve the proofs for a given user, one per tree they are in
+#[instrument(level = "error")]
+async fn get_all_user_info(
+    State(state): State<Arc<RouterState>>,
+    Path(user_pubkey): Path<String>,
+) -> Result<impl IntoResponse> {
+    let merkle_tree = &state.tree;
+    let proofs = get_user_proofs(merkle_tree, user_pubkey)?;
+    Ok(Json(proofs))
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ClaimStatusResp {
     /// Authority that claimed the tokens.
@@ -228,14 +292,42 @@ pub struct ClaimStatusResp {
     pub distributor: Pubkey,
 }
 
-/// Retrieve the claim status for a user
+/// Retrieve the claim status for a user, in the first tree they claimed from
 #[instrument(level = "error")]
 async fn get_claim_status(
     State(state): State<Arc<RouterState>>,
     Path(user_pubkey): Path<String>,
 ) -> Result<impl IntoResponse> {
-    match state.cache.get_claim_status(&user_pubkey) {
-        Some(data) => Ok(Json(ClaimStatusResp {
+    let claim_status = get_user_claim_statuses(&state, &user_pubkey)?
+        .into_iter()
+        .next()
+        .ok_or(ApiError::UserNotFound(user_pubkey))?;
+    Ok(Json(claim_status))
+}
+
+/// Retrieve the claim statuses of a user, one per tree they claimed from
+#[instrument(level = "error")]
+async fn get_claim_statuses(
+    State(state): State<Arc<RouterState>>,
+    Path(user_pubkey): Path<String>,
+) -> Result<impl IntoResponse> {
+    let claim_statuses = get_user_claim_statuses(&state, &user_pubkey)?;
+    if claim_statuses.is_empty() {
+        return Err(ApiError::UserNotFound(user_pubkey));
+    }
+    Ok(Json(claim_statuses))
+}
+
+/// Cached claim statuses of a user, one per tree they claimed from
+fn get_user_claim_statuses(state: &RouterState, user_pubkey: &str) -> Result<Vec<ClaimStatusResp>> {
+    let claimant = Pubkey::from_str(user_pubkey)?;
+    Ok(state
+        .tree
+        .get(&claimant)
+        .into_iter()
+        .flatten()
+        .filter_map(|(distributor, _node)| state.cache.get_claim_status(&claimant, distributor))
+        .map(|data| ClaimStatusResp {
             claimant: data.data.claimant,
             locked_amount: data.data.locked_amount,
             locked_amount_withdrawn: data.data.locked_amount_withdrawn,
@@ -243,9 +335,8 @@ async fn get_claim_status(
             unlocked_amount_claimed: data.data.unlocked_amount_claimed,
             closable: data.data.closable,
             distributor: data.data.distributor,
-        })),
-        None => Err(ApiError::UserNotFound(user_pubkey).into()),
-    }
+        })
+        .collect())
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -256,13 +347,16 @@ pub struct EligibilityResp {
     pub merkle_tree: String,
     /// Mint of token being distributed
     pub mint: String,
-    /// Claim start time from [MerkleDistributor] (Unix Timestamp)
+    /// Claim start time from [MerkleDistributor], or the claimant's own vesting schedule (Unix Timestamp)
     pub start_ts: i64,
-    /// Claim start time from [MerkleDistributor] (Unix Timestamp)
+    /// Claim end time from [MerkleDistributor], or the claimant's own vesting schedule (Unix Timestamp)
     pub end_ts: i64,
+    /// Deadline for a new claim from [MerkleDistributor], after which only locked amounts of existing claims
+    /// can be withdrawn. 0 if new claims are accepted until clawback (Unix Timestamp)
+    pub claim_end_ts: i64,
     /// Claimant's proof of inclusion in the Merkle Tree
     pub proof: Vec<[u8; 32]>,
-    /// Amount user can claim at the beginning, start_amount = amount * START_PCT
+    /// Amount user can claim at the beginning, start_amount = amount * start_claim_pct
     pub start_amount: u128,
     /// Amount user can claim at the end (max bonus)
     pub end_amount: u128,
@@ -278,107 +372,553 @@ pub struct EligibilityResp {
     pub locked_amount: u128,
     /// Amount user has unlocked so far
     pub claimable_amount: u128,
+    /// Whether the user has claimed.
+    /// Bitmap distributors don't track claimed amounts, so the claimed amounts are 0 for them
+    pub claimed: bool,
+    /// Lamports the payer of the new claim sends to claim_fee_receiver, 0 once claimed or without a fee
+    pub claim_fee_lamports: u64,
+    /// Cut of claimable_amount a new claim now sends to claim_fee_receiver instead of the user, 0 once claimed
+    /// or without a fee
+    pub claim_fee_amount: u128,
+    /// Receiver of the claim fee, to pass to new_claim. Default pubkey if the distributor has no fee
+    pub claim_fee_receiver: String,
 }
 
-/// Retrieve the claim status for a user
+/// Reads whether a leaf of a bitmap mode distributor has been claimed, straight from its [ClaimBitmap]
+async fn is_leaf_claimed(
+    state: &RouterState,
+    distributor: &Pubkey,
+    leaf_index: u64,
+) -> Result<bool> {
+    let (claim_bitmap, _bump) = get_claim_bitmap_pda(
+        &state.program_id,
+        distributor,
+        ClaimBitmap::bitmap_index(leaf_index),
+    );
+    let account = state
+        .rpc_client
+        .get_account_with_commitment(&claim_bitmap, state.rpc_client.commitment())
+        .await?
+        .value;
+
+    match account {
+        Some(account) => {
+            if account.data.len() != ClaimBitmap::LEN
+                || account.data[..8] != ClaimBitmap::DISCRIMINATOR
+            {
+                return Err(ApiError::MerkleDistributorError(format!(
+                    "invalid claim bitmap {claim_bitmap}"
+                )));
+            }
+            let bitmap: ClaimBitmap = bytemuck::pod_read_unaligned(&account.data[8..]);
+            Ok(bitmap
+                .is_claimed(leaf_index)
+                .map_err(|err| ApiError::InternalError(Box::new(err)))?)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Retrieve the eligibility of a user, in the first tree they are in
 #[instrument(level = "error")]
 async fn get_eligibility(
     State(state): State<Arc<RouterState>>,
     Path(user_pubkey): Path<String>,
 ) -> Result<impl IntoResponse> {
-    let merkle_tree = &state.tree;
-    let proof = get_user_proof(merkle_tree, user_pubkey.clone())?;
+    let proof = get_user_proofs(&state.tree, user_pubkey.clone())?
+        .into_iter()
+        .next()
+        .ok_or(ApiError::UserNotFound(user_pubkey.clone()))?;
+    let eligibility = get_tree_eligibility(&state, &user_pubkey, proof).await?;
+    Ok(Json(eligibility))
+}
+
+/// Retrieve the eligibility of a user, one per tree they are in
+#[instrument(level = "error")]
+async fn get_eligibilities(
+    State(state): State<Arc<RouterState>>,
+    Path(user_pubkey): Path<String>,
+) -> Result<impl IntoResponse> {
+    let proofs = get_user_proofs(&state.tree, user_pubkey.clone())?;
+    let mut eligibilities = Vec::with_capacity(proofs.len());
+    for proof in proofs {
+        eligibilities.push(get_tree_eligibility(&state, &user_pubkey, proof).await?);
+    }
+    Ok(Json(eligibilities))
+}
+
+/// Eligibility of a user in one of the trees they are in, from its proof
+async fn get_tree_eligibility(
+    state: &RouterState,
+    user_pubkey: &str,
+    proof: UserProof,
+) -> Result<EligibilityResp> {
     let distributor = state.cache.get_distributor(&proof.merkle_tree);
     let curr_ts = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("epoch time")
         .as_secs() as i64;
 
-    let (start_ts, end_ts, mint) = match distributor {
-        Some(distributor) => (
-            distributor.start_ts,
-            distributor.end_ts,
-            distributor.mint.to_string(),
-        ),
-        None => (
-            state.cache.default_start_ts,
-            state.cache.default_end_ts,
-            state.cache.default_mint.clone(),
-        ),
+    let bitmap_claimed = match &distributor {
+        Some(distributor) if distributor.claim_mode == ClaimMode::Bitmap => {
+            let leaf_index = proof
+                .leaf_index
+                .ok_or(ApiError::ProofNotFound(user_pubkey.to_string()))?;
+            is_leaf_claimed(state, &Pubkey::from_str(&proof.merkle_tree)?, leaf_index).await?
+        }
+        _ => false,
     };
-    let (unlocked_amount_claimed, locked_amount_withdrawn, claimable_amount) = state
-        .cache
-        .get_claim_status(&user_pubkey)
-        .map(|r| {
-            (
-                r.data.unlocked_amount_claimed,
-                r.data.locked_amount_withdrawn,
-                r.data
-                    .amount_withdrawable(curr_ts, start_ts, end_ts)
-                    .unwrap_or(0),
-            )
-        })
-        .unwrap_or_else(|| {
-            // No ClaimStatus exists - calculate what would be claimable
-            // For unlocked amounts (proof.amount), use vesting with 50% start
-            let unlocked_claimable = calculate_claimable_amount_for_new_user(
-                proof.amount as u64,
-                curr_ts,
-                start_ts,
-                end_ts,
-                state.start_amount_pct,
-            );
 
-            // For locked amounts, calculate linear vesting (no 50% start)
-            let locked_claimable = calculate_locked_amount_claimable(
-                proof.locked_amount as u64,
-                curr_ts,
-                start_ts,
-                end_ts,
-            );
+    let (start_ts, end_ts, mint, start_claim_pct, cliff_ts, unlock_period, claim_end_ts) =
+        match &distributor {
+            Some(distributor) => (
+                distributor.start_ts,
+                distributor.end_ts,
+                distributor.mint.to_string(),
+                distributor.get_start_claim_pct(),
+                distributor.cliff_ts,
+                distributor.unlock_period,
+                distributor.claim_end_ts,
+            ),
+            None => (
+                state.cache.default_start_ts,
+                state.cache.default_end_ts,
+                state.cache.default_mint.clone(),
+                state.cache.default_start_claim_pct,
+                0,
+                0,
+                0,
+            ),
+        };
+    // leaves committing to their own vesting schedule override the distributor's
+    let (start_ts, end_ts) = match (proof.start_ts, proof.end_ts) {
+        (Some(leaf_start_ts), Some(leaf_end_ts)) => (leaf_start_ts, leaf_end_ts),
+        _ => (start_ts, end_ts),
+    };
+    let claim_status = state.cache.get_claim_status(
+        &Pubkey::from_str(user_pubkey)?,
+        &Pubkey::from_str(&proof.merkle_tree)?,
+    );
+    let claimed = bitmap_claimed || claim_status.is_some();
+    let (unlocked_amount_claimed, locked_amount_withdrawn, claimable_amount, claim_fee_amount) =
+        claim_status
+            .map(|r| {
+                (
+                    r.data.unlocked_amount_claimed,
+                    r.data.locked_amount_withdrawn,
+                    r.data
+                        .amount_withdrawable(curr_ts, start_ts, end_ts, cliff_ts, unlock_period)
+                        .unwrap_or(0),
+                    0,
+                )
+            })
+            .unwrap_or_else(|| {
+                if bitmap_claimed || (claim_end_ts != 0 && curr_ts >= claim_end_ts) {
+                    return (0, 0, 0, 0);
+                }
+
+                // No ClaimStatus exists - calculate what would be claimable
+                // For unlocked amounts (proof.amount), use vesting with start_claim_pct up front
+                let unlocked_claimable = calculate_claimable_amount_for_new_user(
+                    proof.amount as u64,
+                    curr_ts,
+                    start_ts,
+                    end_ts,
+                    start_claim_pct,
+                );
+
+                // For locked amounts, calculate vesting after the cliff (no 50% start)
+                let locked_claimable = calculate_locked_amount_claimable(
+                    proof.locked_amount as u64,
+                    curr_ts,
+                    start_ts,
+                    end_ts,
+                    cliff_ts,
+                    unlock_period,
+                );
 
-            (0, 0, unlocked_claimable + locked_claimable)
-        });
+                // the claim fee is only cut from the unlocked amount
+                let claim_fee_amount = distributor.as_ref().map_or(0, |distributor| {
+                    distributor.get_claim_fee_amount(unlocked_claimable)
+                });
+
+                (
+                    0,
+                    0,
+                    unlocked_claimable + locked_claimable,
+                    claim_fee_amount,
+                )
+            });
+    let (claim_fee_lamports, claim_fee_receiver) = match &distributor {
+        Some(distributor) if distributor.has_claim_fee() && !claimed => (
+            distributor.claim_fee_lamports,
+            distributor.claim_fee_receiver,
+        ),
+        _ => (0, Pubkey::default()),
+    };
 
-    let start_amount_pct_num = state.start_amount_pct * START_AMOUNT_PCT_PRECISION;
     let start_amount = (proof.amount as u128)
-        .checked_mul(start_amount_pct_num)
+        .checked_mul(start_claim_pct)
         .ok_or_else(|| {
             let err = ApiError::MathError();
             error!(
-                "Math error occurred (1), amount: {}, START_AMOUNT_PCT_NUM: {}, START_AMOUNT_PCT_DENOM: {}",
-                proof.amount, start_amount_pct_num, START_AMOUNT_PCT_DENOM
+                "Math error occurred (1), amount: {}, start_claim_pct: {}, PCT_PRECISION: {}",
+                proof.amount, start_claim_pct, PCT_PRECISION
             );
             err
         })?
-        .checked_div(START_AMOUNT_PCT_DENOM)
+        .checked_div(PCT_PRECISION)
         .ok_or_else(|| {
             let err = ApiError::MathError();
             error!(
-                "Math error occurred (2), amount: {}, START_AMOUNT_PCT_NUM: {}, START_AMOUNT_PCT_DENOM: {}",
-                proof.amount, start_amount_pct_num, START_AMOUNT_PCT_DENOM
+                "Math error occurred (2), amount: {}, start_claim_pct: {}, PCT_PRECISION: {}",
+                proof.amount, start_claim_pct, PCT_PRECISION
             );
             err
         })?;
 
-    Ok(Json(EligibilityResp {
-        claimant: user_pubkey.clone(),
+    Ok(EligibilityResp {
+        claimant: user_pubkey.to_string(),
         merkle_tree: proof.merkle_tree,
         start_ts,
         end_ts,
+        claim_end_ts,
         mint,
         proof: proof.proof,
         start_amount,
         end_amount: proof.amount as u128,
         locked_amount: proof.locked_amount as u128,
         claimable_amount: claimable_amount as u128,
-        unvested_amount: state.cache.get_unvested_amount(user_pubkey),
+        unvested_amount: state.cache.get_unvested_amount(user_pubkey.to_string()),
         claimed_amount: (unlocked_amount_claimed + locked_amount_withdrawn) as u128,
         unlocked_amount_claimed: unlocked_amount_claimed as u128,
         locked_amount_withdrawn: locked_amount_withdrawn as u128,
+        claimed,
+        claim_fee_lamports,
+        claim_fee_amount: claim_fee_amount as u128,
+        claim_fee_receiver: claim_fee_receiver.to_string(),
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SponsoredClaimTxResp {
+    /// Relayer paying the fees and the claim status rent
+    pub payer: String,
+    /// Base64 encoded new_claim transaction, signed by the relayer and awaiting the claimant's signature
+    pub transaction: String,
+}
+
+/// Build a new_claim (or new_bitmap_claim) transaction for a user, paid for and partially signed by the relayer.
+/// Users in several trees get a batch_claim transaction instead, see [get_sponsored_batch_claim_tx]
+#[instrument(level = "error")]
+async fn get_sponsored_claim_tx(
+    State(state): State<Arc<RouterState>>,
+    Path(user_pubkey): Path<String>,
+) -> Result<impl IntoResponse> {
+    let relayer = state
+        .relayer
+        .as_ref()
+        .ok_or(ApiError::RelayerNotConfigured())?;
+
+    let mut proofs = get_user_proofs(&state.tree, user_pubkey.clone())?;
+    if proofs.len() > 1 {
+        return Ok(Json(
+            get_sponsored_batch_claim_tx(&state, relayer, user_pubkey, proofs).await?,
+        ));
+    }
+    let proof = proofs.remove(0);
+    let distributor = state.cache.get_distributor(&proof.merkle_tree).ok_or(
+        ApiError::MerkleDistributorNotFound(proof.merkle_tree.clone()),
+    )?;
+    let distributor_pubkey = Pubkey::from_str(&proof.merkle_tree)?;
+    let claimant = Pubkey::from_str(&user_pubkey)?;
+
+    let token_program = state.rpc_client.get_account(&distributor.mint).await?.owner;
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &distributor.mint, &token_program);
+
+    let claim_ix = match distributor.claim_mode {
+        ClaimMode::ClaimStatus => {
+            if state
+                .cache
+                .get_claim_status(&claimant, &distributor_pubkey)
+                .is_some()
+            {
+                return Err(ApiError::ClaimStatusExists(user_pubkey));
+            }
+            let (claim_status, _bump) =
+                get_claim_status_pda(&state.program_id, &claimant, &distributor_pubkey);
+            // settled claims closed to a tombstone are not in the cache but still block new_claim
+            if state.rpc_client.get_account(&claim_status).await.is_ok() {
+                return Err(ApiError::ClaimStatusExists(user_pubkey));
+            }
+
+            Instruction {
+                program_id: state.program_id,
+                accounts: merkle_distributor::accounts::NewClaim {
+                    distributor: distributor_pubkey,
+                    claim_status,
+                    from: distributor.token_vault,
+                    to: claimant_ata,
+                    claimant,
+                    payer: relayer.pubkey(),
+                    mint: distributor.mint,
+                    token_program,
+                    system_program: system_program::ID,
+                    claim_fee_receiver: distributor
+                        .has_claim_fee()
+                        .then_some(distributor.claim_fee_receiver),
+                    event_authority: get_event_authority_pda(&state.program_id).0,
+                    program: state.program_id,
+                }
+                .to_account_metas(None),
+                data: match (proof.start_ts, proof.end_ts) {
+                    (Some(start_ts), Some(end_ts)) => {
+                        merkle_distributor::instruction::NewClaimWithVesting {
+                            amount_unlocked: proof.amount,
+                            amount_locked: proof.locked_amount,
+                            start_ts,
+                            end_ts,
+                            proof: proof.proof,
+                        }
+                        .data()
+                    }
+                    _ => merkle_distributor::instruction::NewClaim {
+                        amount_unlocked: proof.amount,
+                        amount_locked: proof.locked_amount,
+                        proof: proof.proof,
+                    }
+                    .data(),
+                },
+            }
+        }
+        ClaimMode::Bitmap => {
+            let leaf_index = proof
+                .leaf_index
+                .ok_or(ApiError::ProofNotFound(user_pubkey.clone()))?;
+            if is_leaf_claimed(&state, &distributor_pubkey, leaf_index).await? {
+                return Err(ApiError::ClaimStatusExists(user_pubkey));
+            }
+            let (claim_bitmap, _bump) = get_claim_bitmap_pda(
+                &state.program_id,
+                &distributor_pubkey,
+                ClaimBitmap::bitmap_index(leaf_index),
+            );
+
+            // no account to create for the claim, the relayer only pays the fees and the ATA
+            Instruction {
+                program_id: state.program_id,
+                accounts: merkle_distributor::accounts::NewBitmapClaim {
+                    distributor: distributor_pubkey,
+                    claim_bitmap,
+                    from: distributor.token_vault,
+                    to: claimant_ata,
+                    claimant,
+                    mint: distributor.mint,
+                    token_program,
+                    event_authority: get_event_authority_pda(&state.program_id).0,
+                    program: state.program_id,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::NewBitmapClaim {
+                    leaf_index,
+                    amount_unlocked: proof.amount,
+                    proof: proof.proof,
+                }
+                .data(),
+            }
+        }
+    };
+
+    let ixs = [
+        create_associated_token_account_idempotent(
+            &relayer.pubkey(),
+            &claimant,
+            &distributor.mint,
+            &token_program,
+        ),
+        claim_ix,
+    ];
+
+    let blockhash = state.rpc_client.get_latest_blockhash().await?;
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&relayer.pubkey()));
+    tx.partial_sign(&[relayer], blockhash);
+
+    let serialized = bincode::serialize(&tx)?;
+
+    Ok(Json(SponsoredClaimTxResp {
+        payer: relayer.pubkey().to_string(),
+        transaction: base64::encode(serialized),
     }))
 }
 
+/// Compute units budgeted for each claim of a batch_claim
+const COMPUTE_UNITS_PER_CLAIM: u32 = 100_000;
+
+/// Build a batch_claim transaction sweeping the distributors a user is in, paid for and partially signed by the relayer.
+/// Creates the claims not made yet and withdraws the unlocked tokens of the others. Bitmap and per-leaf vesting
+/// distributors, those of another mint than the first, and new claims charging a claim fee to another receiver than
+/// the first are left out. The relayer pays any lamport claim fee. Claims that don't fit in one transaction are left
+/// for the next call, and a first claim too large to fit on its own is an error.
+async fn get_sponsored_batch_claim_tx(
+    state: &RouterState,
+    relayer: &Keypair,
+    user_pubkey: String,
+    proofs: Vec<UserProof>,
+) -> Result<SponsoredClaimTxResp> {
+    let claimant = Pubkey::from_str(&user_pubkey)?;
+    let curr_ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("epoch time")
+        .as_secs() as i64;
+
+    let mut mint = None;
+    let mut leaves = vec![];
+    for proof in proofs {
+        let distributor = state.cache.get_distributor(&proof.merkle_tree).ok_or(
+            ApiError::MerkleDistributorNotFound(proof.merkle_tree.clone()),
+        )?;
+        if distributor.claim_mode != ClaimMode::ClaimStatus
+            || distributor.leaf_vesting
+            || *mint.get_or_insert(distributor.mint) != distributor.mint
+        {
+            continue;
+        }
+        let distributor_pubkey = Pubkey::from_str(&proof.merkle_tree)?;
+        let (claim_status, _bump) =
+            get_claim_status_pda(&state.program_id, &claimant, &distributor_pubkey);
+        leaves.push((proof, distributor, distributor_pubkey, claim_status));
+    }
+    let mint = mint.ok_or(ApiError::MerkleDistributorError(format!(
+        "no distributor of {user_pubkey} supports batch claims"
+    )))?;
+
+    let claim_statuses: Vec<_> = leaves.iter().map(|leaf| leaf.3).collect();
+    let claim_status_accounts = state
+        .rpc_client
+        .get_multiple_accounts(&claim_statuses)
+        .await?;
+
+    let mut claim_fee_receiver = None;
+    let mut claims = vec![];
+    for ((proof, distributor, distributor_pubkey, claim_status), account) in
+        leaves.into_iter().zip(claim_status_accounts)
+    {
+        let claim = match account {
+            // settled claims closed to a tombstone no longer deserialize and have nothing left to withdraw
+            Some(account) => {
+                let amount = ClaimStatus::try_deserialize_any_layout(&account.data)
+                    .and_then(|claim_status| {
+                        claim_status.amount_withdrawable(
+                            curr_ts,
+                            distributor.start_ts,
+                            distributor.end_ts,
+                            distributor.cliff_ts,
+                            distributor.unlock_period,
+                        )
+                    })
+                    .unwrap_or(0);
+                if amount == 0 {
+                    continue;
+                }
+                BatchClaimArgs {
+                    amount_unlocked: 0,
+                    amount_locked: 0,
+                    proof: vec![],
+                }
+            }
+            // a batch_claim takes a single claim fee receiver
+            None if distributor.has_claim_fee()
+                && *claim_fee_receiver.get_or_insert(distributor.claim_fee_receiver)
+                    != distributor.claim_fee_receiver =>
+            {
+                continue;
+            }
+            None => BatchClaimArgs {
+                amount_unlocked: proof.amount,
+                amount_locked: proof.locked_amount,
+                proof: proof.proof,
+            },
+        };
+        claims.push((
+            [
+                AccountMeta::new(distributor_pubkey, false),
+                AccountMeta::new(claim_status, false),
+                AccountMeta::new(distributor.token_vault, false),
+            ],
+            claim,
+        ));
+    }
+    if claims.is_empty() {
+        return Err(ApiError::ClaimStatusExists(user_pubkey));
+    }
+
+    let token_program = state.rpc_client.get_account(&mint).await?.owner;
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &mint, &token_program);
+
+    let build_tx = |claims: &[([AccountMeta; 3], BatchClaimArgs)]| {
+        let mut accounts = merkle_distributor::accounts::BatchClaim {
+            to: claimant_ata,
+            claimant,
+            payer: relayer.pubkey(),
+            mint,
+            token_program,
+            system_program: system_program::ID,
+            claim_fee_receiver,
+            event_authority: get_event_authority_pda(&state.program_id).0,
+            program: state.program_id,
+        }
+        .to_account_metas(None);
+        accounts.extend(claims.iter().flat_map(|(metas, _)| metas.clone()));
+
+        let ixs = [
+            ComputeBudgetInstruction::set_compute_unit_limit(
+                (COMPUTE_UNITS_PER_CLAIM * claims.len() as u32).min(1_400_000),
+            ),
+            create_associated_token_account_idempotent(
+                &relayer.pubkey(),
+                &claimant,
+                &mint,
+                &token_program,
+            ),
+            Instruction {
+                program_id: state.program_id,
+                accounts,
+                data: merkle_distributor::instruction::BatchClaim {
+                    claims: claims.iter().map(|(_, claim)| claim.clone()).collect(),
+                }
+                .data(),
+            },
+        ];
+        Transaction::new_with_payer(&ixs, Some(&relayer.pubkey()))
+    };
+
+    let num_claims = claims_fitting_in_packet(&claims, &build_tx)?;
+    let mut tx = build_tx(&claims[..num_claims]);
+    let blockhash = state.rpc_client.get_latest_blockhash().await?;
+    tx.partial_sign(&[relayer], blockhash);
+
+    Ok(SponsoredClaimTxResp {
+        payer: relayer.pubkey().to_string(),
+        transaction: base64::encode(bincode::serialize(&tx)?),
+    })
+}
+
+/// Number of leading `claims` whose transaction built by `build_tx` fits in a packet. Proofs make new claims much
+/// larger than withdrawals, so the last claims are dropped until the rest fit. Errors if the first claim alone doesn't.
+fn claims_fitting_in_packet<T>(
+    claims: &[T],
+    build_tx: impl Fn(&[T]) -> Transaction,
+) -> Result<usize> {
+    for num_claims in (1..=claims.len()).rev() {
+        if bincode::serialize(&build_tx(&claims[..num_claims]))?.len() <= PACKET_DATA_SIZE {
+            return Ok(num_claims);
+        }
+    }
+    Err(ApiError::TransactionTooLarge())
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SingleDistributor {
     pub distributor_pubkey: String,
@@ -446,8 +986,12 @@ pub struct MerkleDistributorResp {
     pub total_amount_claimed: u64,
     /// Total amount of tokens that have been forgone.
     pub total_amount_forgone: u64,
+    /// Total amount of forgone tokens the admin has withdrawn.
+    pub total_amount_forgone_withdrawn: u64,
     /// Number of nodes that have been claimed.
     pub num_nodes_claimed: u64,
+    /// Number of claim statuses closed by their claimant once fully withdrawn
+    pub num_nodes_settled: u64,
     /// Lockup time start (Unix Timestamp)
     pub start_ts: i64,
     /// Lockup time end (Unix Timestamp)
@@ -458,16 +1002,69 @@ pub struct MerkleDistributorResp {
     pub clawback_receiver: String,
     /// Admin wallet
     pub admin: String,
+    /// Admin proposed by the current admin, default pubkey if none
+    pub pending_admin: String,
+    /// Key allowed to set the enable slot and pause, default pubkey if none
+    pub operator: String,
+    /// Program claimed tokens can be deposited into with new_claim_and_deposit, default pubkey if none
+    pub deposit_program: String,
     /// Whether or not the distributor has been clawed back
     pub clawed_back: bool,
+    /// Whether the admin paused claims and clawback
+    pub paused: bool,
     /// this merkle tree is enable from this slot
     pub enable_slot: u64,
     /// indicate that whether admin can close this pool, for testing purpose
     pub closable: bool,
+    /// Share of the unlocked amount claimable at start_ts, scaled by PCT_PRECISION
+    pub start_claim_pct: u128,
+    /// Locked amounts don't unlock before this timestamp, 0 for no cliff
+    pub cliff_ts: i64,
+    /// Locked amounts unlock in steps of this many seconds, 0 for a linear unlock
+    pub unlock_period: i64,
+    /// Whether each claimant has their own vesting schedule, see the eligibility endpoint
+    pub leaf_vesting: bool,
+    /// Whether locked amounts owed to claimants stay withdrawable after clawback
+    pub preserve_locked_on_clawback: bool,
+    /// Locked amounts owed to claimants, kept in the vault on clawback if preserved
+    pub total_locked_outstanding: u64,
+    /// Number of times the root was corrected, 0 for the original root
+    pub root_revision: u64,
+    /// New claims are rejected from this timestamp, 0 if accepted until clawback
+    pub claim_end_ts: i64,
+    /// What the distributor is for, if the admin set its metadata
+    pub metadata: Option<DistributorMetadataResp>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DistributorMetadataResp {
+    /// Display name of the campaign
+    pub name: String,
+    /// Off-chain identifier of the campaign
+    pub campaign_id: String,
+    /// URI of the published tree manifest
+    pub uri: String,
+    /// SHA-256 hash of the published merkle tree, base58 encoded
+    pub content_hash: String,
+}
+
+impl From<DistributorMetadata> for DistributorMetadataResp {
+    fn from(metadata: DistributorMetadata) -> Self {
+        DistributorMetadataResp {
+            name: metadata.name,
+            campaign_id: metadata.campaign_id,
+            uri: metadata.uri,
+            content_hash: Hash::new_from_array(metadata.content_hash).to_string(),
+        }
+    }
 }
 
 impl MerkleDistributorResp {
-    fn from(pubkey: String, distributor: MerkleDistributor) -> Self {
+    fn from(
+        pubkey: String,
+        distributor: MerkleDistributor,
+        metadata: Option<DistributorMetadata>,
+    ) -> Self {
         MerkleDistributorResp {
             pubkey,
             version: distributor.version,
@@ -478,15 +1075,30 @@ impl MerkleDistributorResp {
             max_num_nodes: distributor.max_num_nodes,
             total_amount_claimed: distributor.total_amount_claimed,
             total_amount_forgone: distributor.total_amount_forgone,
+            total_amount_forgone_withdrawn: distributor.total_amount_forgone_withdrawn,
             num_nodes_claimed: distributor.num_nodes_claimed,
+            num_nodes_settled: distributor.num_nodes_settled,
             start_ts: distributor.start_ts,
             end_ts: distributor.end_ts,
             clawback_start_ts: distributor.clawback_start_ts,
             clawback_receiver: distributor.clawback_receiver.to_string(),
             admin: distributor.admin.to_string(),
+            pending_admin: distributor.pending_admin.to_string(),
+            operator: distributor.operator.to_string(),
+            deposit_program: distributor.deposit_program.to_string(),
             clawed_back: distributor.clawed_back,
+            paused: distributor.paused,
             enable_slot: distributor.enable_slot,
             closable: distributor.closable,
+            start_claim_pct: distributor.get_start_claim_pct(),
+            cliff_ts: distributor.cliff_ts,
+            unlock_period: distributor.unlock_period,
+            leaf_vesting: distributor.leaf_vesting,
+            preserve_locked_on_clawback: distributor.clawback_mode == ClawbackMode::PreserveLocked,
+            total_locked_outstanding: distributor.total_locked_outstanding,
+            root_revision: distributor.root_revision,
+            claim_end_ts: distributor.claim_end_ts,
+            metadata: metadata.map(DistributorMetadataResp::from),
         }
     }
 }
@@ -497,7 +1109,10 @@ async fn get_distributors(State(state): State<Arc<RouterState>>) -> Result<impl
             .cache
             .get_all_distributors()
             .into_iter()
-            .map(|(k, v)| MerkleDistributorResp::from(k, v))
+            .map(|(k, v)| {
+                let metadata = state.cache.get_distributor_metadata(&k);
+                MerkleDistributorResp::from(k, v, metadata)
+            })
             .collect::<Vec<MerkleDistributorResp>>(),
     ))
 }
@@ -508,25 +1123,27 @@ async fn root() -> impl IntoResponse {
 
 #[cfg(test)]
 mod tests {
+    use merkle_distributor::state::claim_status::ClaimStatus;
+
     use super::*;
 
     #[test]
     fn test_calculate_claimable_amount_before_start() {
-        let amount = calculate_claimable_amount_for_new_user(1_000_000, 0, 10, 100, 50);
+        let amount = calculate_claimable_amount_for_new_user(1_000_000, 0, 10, 100, 500_000);
         assert_eq!(amount, 0);
     }
 
     #[test]
     fn test_calculate_claimable_amount_at_start() {
-        // At start time, should get 50% (start_amount_pct = 50)
-        let amount = calculate_claimable_amount_for_new_user(1_000_000, 10, 10, 100, 50);
+        // At start time, should get 50% (start_claim_pct = 500_000)
+        let amount = calculate_claimable_amount_for_new_user(1_000_000, 10, 10, 100, 500_000);
         assert_eq!(amount, 500_000);
     }
 
     #[test]
     fn test_calculate_claimable_amount_halfway() {
         // Halfway through vesting period
-        let amount = calculate_claimable_amount_for_new_user(1_000_000, 55, 10, 100, 50);
+        let amount = calculate_claimable_amount_for_new_user(1_000_000, 55, 10, 100, 500_000);
         // 50% start + 25% of remaining 50% = 75%
         assert_eq!(amount, 750_000);
     }
@@ -534,40 +1151,81 @@ mod tests {
     #[test]
     fn test_calculate_claimable_amount_at_end() {
         // At end time, should get 100%
-        let amount = calculate_claimable_amount_for_new_user(1_000_000, 100, 10, 100, 50);
+        let amount = calculate_claimable_amount_for_new_user(1_000_000, 100, 10, 100, 500_000);
         assert_eq!(amount, 1_000_000);
     }
 
     #[test]
     fn test_calculate_claimable_amount_after_end() {
         // After end time, should still get 100%
-        let amount = calculate_claimable_amount_for_new_user(1_000_000, 200, 10, 100, 50);
+        let amount = calculate_claimable_amount_for_new_user(1_000_000, 200, 10, 100, 500_000);
         assert_eq!(amount, 1_000_000);
     }
 
     #[test]
     fn test_calculate_claimable_amount_zero_duration() {
         // Edge case: start_ts == end_ts
-        let amount = calculate_claimable_amount_for_new_user(1_000_000, 10, 10, 10, 50);
+        let amount = calculate_claimable_amount_for_new_user(1_000_000, 10, 10, 10, 500_000);
         assert_eq!(amount, 1_000_000);
     }
 
     #[test]
     fn test_calculate_locked_amount_claimable() {
         // Test linear vesting for locked amounts
-        let amount = calculate_locked_amount_claimable(1_000_000, 0, 10, 100);
+        let amount = calculate_locked_amount_claimable(1_000_000, 0, 10, 100, 0, 0);
         assert_eq!(amount, 0);
 
-        let amount = calculate_locked_amount_claimable(1_000_000, 10, 10, 100);
+        let amount = calculate_locked_amount_claimable(1_000_000, 10, 10, 100, 0, 0);
         assert_eq!(amount, 0); // At start, 0% vested
 
-        let amount = calculate_locked_amount_claimable(1_000_000, 55, 10, 100);
+        let amount = calculate_locked_amount_claimable(1_000_000, 55, 10, 100, 0, 0);
         assert_eq!(amount, 500_000); // Halfway through, 50% vested
 
-        let amount = calculate_locked_amount_claimable(1_000_000, 100, 10, 100);
+        let amount = calculate_locked_amount_claimable(1_000_000, 100, 10, 100, 0, 0);
         assert_eq!(amount, 1_000_000); // At end, 100% vested
     }
 
+    #[test]
+    fn test_calculate_locked_amount_claimable_with_cliff() {
+        let amount = calculate_locked_amount_claimable(1_000_000, 45, 10, 100, 46, 0);
+        assert_eq!(amount, 0); // Before the cliff, nothing is vested
+
+        let amount = calculate_locked_amount_claimable(1_000_000, 46, 10, 100, 46, 0);
+        assert_eq!(amount, 400_000); // At the cliff, everything accrued since start is vested
+
+        let amount = calculate_locked_amount_claimable(1_000_000, 100, 10, 100, 46, 0);
+        assert_eq!(amount, 1_000_000);
+    }
+
+    #[test]
+    fn test_calculate_locked_amount_claimable_with_unlock_period() {
+        let amount = calculate_locked_amount_claimable(1_000_000, 39, 10, 100, 0, 30);
+        assert_eq!(amount, 0); // First period not over yet
+
+        let amount = calculate_locked_amount_claimable(1_000_000, 40, 10, 100, 0, 30);
+        assert_eq!(amount, 333_333);
+
+        let amount = calculate_locked_amount_claimable(1_000_000, 99, 10, 100, 0, 30);
+        assert_eq!(amount, 666_666);
+
+        let amount = calculate_locked_amount_claimable(1_000_000, 100, 10, 100, 0, 30);
+        assert_eq!(amount, 1_000_000);
+
+        // Matches the on-chain ClaimStatus::unlocked_amount
+        let claim_status = ClaimStatus {
+            locked_amount: 1_000_000,
+            ..ClaimStatus::default()
+        };
+        for curr_ts in [0, 10, 45, 46, 70, 99, 100] {
+            assert_eq!(
+                calculate_locked_amount_claimable(1_000_000, curr_ts, 10, 100, 46, 30),
+                claim_status
+                    .unlocked_amount(curr_ts, 10, 100, 46, 30)
+                    .unwrap()
+            );
+        }
+    }
+
     #[test]
     fn test_user_case_locked_amount() {
         // Test the specific case from the user
@@ -581,7 +1239,38 @@ mod tests {
         let end_ts = 1747396800;
         let curr_ts = 1750149143; // Past end time
 
-        let claimable = calculate_locked_amount_claimable(locked_amount, curr_ts, start_ts, end_ts);
+        let claimable =
+            calculate_locked_amount_claimable(locked_amount, curr_ts, start_ts, end_ts, 0, 0);
         assert_eq!(claimable, 750000000); // Should be fully vested
     }
+
+    #[test]
+    fn test_claims_fitting_in_packet() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        // one instruction carrying the data of every claim, like batch_claim carries their proofs
+        let build_tx = |claims: &[Vec<u8>]| {
+            let ix = Instruction {
+                program_id,
+                accounts: vec![AccountMeta::new(payer, true)],
+                data: claims.concat(),
+            };
+            Transaction::new_with_payer(&[ix], Some(&payer))
+        };
+
+        let claims = vec![vec![0u8; 400]; 2];
+        assert_eq!(claims_fitting_in_packet(&claims, &build_tx).unwrap(), 2);
+
+        let claims = vec![vec![0u8; 400]; 4];
+        assert_eq!(claims_fitting_in_packet(&claims, &build_tx).unwrap(), 2);
+
+        let claims = vec![vec![0u8; 400], vec![0u8; PACKET_DATA_SIZE]];
+        assert_eq!(claims_fitting_in_packet(&claims, &build_tx).unwrap(), 1);
+
+        let claims = vec![vec![0u8; PACKET_DATA_SIZE], vec![0u8; 400]];
+        assert!(matches!(
+            claims_fitting_in_packet(&claims, &build_tx),
+            Err(ApiError::TransactionTooLarge())
+        ));
+    }
 }
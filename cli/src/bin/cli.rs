@@ -17,9 +17,9 @@ use anchor_client::{
 use anchor_lang::{
     prelude::{Clock, Pubkey},
     solana_program::sysvar,
-    AccountDeserialize, InstructionData, Key, ToAccountMetas,
+    InstructionData, Key, ToAccountMetas,
 };
-use anchor_spl::token::{self, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 use anyhow::Result;
 use bincode::deserialize;
 use clap::{Parser, Subcommand};
@@ -27,9 +27,13 @@ use csv::Writer;
 use jito_merkle_tree::{
     airdrop_merkle_tree::AirdropMerkleTree,
     csv_entry::CsvEntry,
-    utils::{get_claim_status_pda, get_merkle_distributor_pda},
+    utils::{
+        get_claim_bitmap_pda, get_claim_status_pda, get_distributor_metadata_pda,
+        get_event_authority_pda, get_legacy_merkle_distributor_pda, get_merkle_distributor_pda,
+        get_program_config_pda,
+    },
 };
-use merkle_distributor::state::merkle_distributor::MerkleDistributor;
+use merkle_distributor::state::merkle_distributor::{ClaimMode, ClawbackMode, MerkleDistributor};
 use solana_program::{clock::DEFAULT_MS_PER_SLOT, instruction::Instruction};
 use solana_rpc_client::rpc_client::{RpcClient, SerializableTransaction};
 use solana_sdk::{
@@ -39,7 +43,8 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account,
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account,
 };
 
 use crate::instructions::*;
@@ -68,6 +73,11 @@ pub struct Args {
     /// Priority fee
     #[clap(long, env)]
     pub priority: Option<u64>,
+
+    /// Admin that created the distributors, part of their address. Leave unset for distributors created before
+    /// it was, whose address only derives from the mint and version
+    #[clap(long, env)]
+    pub base: Option<Pubkey>,
 }
 
 impl Args {
@@ -84,6 +94,16 @@ impl Args {
             client.program(merkle_distributor::id()).unwrap();
         program
     }
+
+    /// Address of the distributor for `version`, derived from `base` if set.
+    fn get_distributor_pda(&self, version: u64) -> (Pubkey, u8) {
+        match self.base {
+            Some(base) => {
+                get_merkle_distributor_pda(&self.program_id, &base, &self.mint, version)
+            }
+            None => get_legacy_merkle_distributor_pda(&self.program_id, &self.mint, version),
+        }
+    }
 }
 
 // Subcommands
@@ -93,16 +113,43 @@ pub enum Commands {
     Claim(ClaimArgs),
     /// Create a new instance of a merkle distributor
     NewDistributor(NewDistributorArgs),
+    /// Set the keys allowed to create distributors, signed by the program upgrade authority
+    SetCreators(SetCreatorsArgs),
     CloseDistributor(CloseDistributorArgs),
     CloseClaimStatus(CloseClaimStatusArgs),
+    /// Close the keypair's claim status once all its locked tokens are withdrawn, reclaiming the rent
+    CloseSettledClaimStatus(CloseSettledClaimStatusArgs),
     /// Clawback tokens from merkle distributor
     #[clap(hide = true)]
     Clawback(ClawbackArgs),
+    /// Close clawed back merkle distributors and their vaults, returning the rent to the admin
+    FinalizeDistributor(FinalizeDistributorArgs),
+    /// Grow all merkle distributors created with an older account layout to the current one
+    MigrateDistributor(MigrateDistributorArgs),
+    /// Pause claims and clawback on all merkle distributors
+    Pause(PauseArgs),
+    /// Unpause all merkle distributors
+    Unpause(PauseArgs),
+    /// Withdraw forgone tokens from all merkle distributors
+    WithdrawForgone(WithdrawForgoneArgs),
     /// Create a Merkle tree, given a CSV of recipients
     CreateMerkleTree(CreateMerkleTreeArgs),
-    SetAdmin(SetAdminArgs),
+    /// Propose a new admin for all merkle distributors
+    ProposeAdmin(ProposeAdminArgs),
+    /// Accept the admin role on all merkle distributors, signed by the proposed admin
+    AcceptAdmin(AcceptAdminArgs),
+    /// Set the operator allowed to set the enable slot and pause on all merkle distributors
+    SetOperator(SetOperatorArgs),
+    /// Allowlist the program claimed tokens can be deposited into on all merkle distributors
+    SetDepositProgram(SetDepositProgramArgs),
+    /// Set the fee charged on new claims on all merkle distributors
+    SetClaimFee(SetClaimFeeArgs),
+    /// Describe all merkle distributors of a campaign with on-chain metadata
+    SetDistributorMetadata(SetDistributorMetadataArgs),
 
     SetEnableSlot(SetEnableSlotArgs),
+    /// Replace the root of a distributor with a corrected merkle tree, before its enable slot
+    UpdateRoot(UpdateRootArgs),
     SetEnableSlotByTime(SetEnableSlotByTimeArgs),
 
     CreateTestList(CreateTestListArgs),
@@ -140,12 +187,23 @@ pub struct CloseDistributorArgs {
 
 #[derive(Parser, Debug)]
 pub struct CloseClaimStatusArgs {}
+
+#[derive(Parser, Debug)]
+pub struct CloseSettledClaimStatusArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
 // NewClaim and Claim subcommand args
 #[derive(Parser, Debug)]
 pub struct ClaimArgs {
-    /// Merkle distributor path
+    /// Merkle tree file, or a folder of merkle trees to claim from every one the keypair is in with batch claims
     #[clap(long, env)]
     pub merkle_tree_path: PathBuf,
+
+    /// Token account to send the claimed tokens to, defaults to the claimant's ATA
+    #[clap(long, env)]
+    pub destination: Option<Pubkey>,
 }
 
 #[derive(Parser, Debug)]
@@ -215,16 +273,80 @@ pub struct NewDistributorArgs {
     #[clap(long, env)]
     pub closable: bool,
 
+    /// Share of the unlocked amount claimable at start_vesting_ts, scaled by 1_000_000 (500_000 for 50%)
+    #[clap(long, env, default_value_t = 500_000)]
+    pub start_claim_pct: u64,
+
+    /// Locked amounts don't unlock before this timestamp, 0 for no cliff
+    #[clap(long, env, default_value_t = 0)]
+    pub cliff_ts: i64,
+
+    /// Locked amounts unlock in steps of this many seconds (e.g. 2592000 for 30 days), 0 for a linear unlock
+    #[clap(long, env, default_value_t = 0)]
+    pub unlock_period: i64,
+
+    /// Keep locked amounts owed to existing claimants in the vault on clawback, so they can still be withdrawn
+    #[clap(long, env)]
+    pub preserve_locked_on_clawback: bool,
+
+    /// New claims are rejected from this timestamp, while existing claimants keep withdrawing locked amounts.
+    /// At most clawback_start_ts, 0 to accept new claims until clawback
+    #[clap(long, env, default_value_t = 0)]
+    pub claim_end_ts: i64,
+
+    /// Fund each vault with its max total claim from the keypair's token account when creating the distributor,
+    /// instead of with fund-all afterwards
+    #[clap(long, env)]
+    pub fund: bool,
+
     #[clap(long, env)]
     pub skip_verify: bool,
 }
 
+#[derive(Parser, Debug)]
+pub struct UpdateRootArgs {
+    /// Corrected merkle tree file, with the airdrop version of the distributor to update
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
 #[derive(Parser, Debug)]
 pub struct ClawbackArgs {
     #[clap(long, env)]
     pub merkle_tree_path: PathBuf,
 }
 
+#[derive(Parser, Debug)]
+pub struct FinalizeDistributorArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct MigrateDistributorArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct PauseArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct WithdrawForgoneArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+    /// Token account receiving the forgone tokens, defaults to each distributor's clawback receiver
+    #[clap(long, env)]
+    pub destination: Option<Pubkey>,
+}
+
 #[derive(Parser, Debug)]
 pub struct CreateMerkleTreeArgs {
     /// CSV path
@@ -246,6 +368,10 @@ pub struct CreateMerkleTreeArgs {
     pub amount: u64,
     #[clap(long, env)]
     pub decimals: u32,
+
+    /// Track claims in a bitmap instead of a ClaimStatus per claimant, only for unlocked amounts
+    #[clap(long, env)]
+    pub bitmap_claims: bool,
     
     /// Optional starting airdrop version. If not provided, will auto-detect next available version
     #[clap(long, env)]
@@ -253,13 +379,76 @@ pub struct CreateMerkleTreeArgs {
 }
 
 #[derive(Parser, Debug)]
-pub struct SetAdminArgs {
+pub struct ProposeAdminArgs {
     #[clap(long, env)]
     pub new_admin: Pubkey,
     #[clap(long, env)]
     pub merkle_tree_path: PathBuf,
 }
 
+#[derive(Parser, Debug)]
+pub struct AcceptAdminArgs {
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetOperatorArgs {
+    /// New operator, the default pubkey 11111111111111111111111111111111 removes it
+    #[clap(long, env)]
+    pub operator: Pubkey,
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetCreatorsArgs {
+    /// Comma separated keys allowed to create distributors, replacing the current ones
+    #[clap(long, env, value_delimiter = ',')]
+    pub creators: Vec<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetDepositProgramArgs {
+    /// New deposit program, the default pubkey 11111111111111111111111111111111 disables deposits
+    #[clap(long, env)]
+    pub deposit_program: Pubkey,
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetClaimFeeArgs {
+    /// Lamports the payer of each new claim sends to the claim fee receiver
+    #[clap(long, env, default_value_t = 0)]
+    pub claim_fee_lamports: u64,
+    /// Cut of the unlocked amount of each new claim sent to the claim fee receiver, in basis points
+    #[clap(long, env, default_value_t = 0)]
+    pub claim_fee_bps: u16,
+    /// Wallet receiving a lamport fee, or token account of the mint receiving a basis point fee.
+    /// Not needed to remove the fee
+    #[clap(long, env)]
+    pub claim_fee_receiver: Option<Pubkey>,
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetDistributorMetadataArgs {
+    /// Display name of the campaign
+    #[clap(long, env)]
+    pub name: String,
+    /// Off-chain identifier of the campaign
+    #[clap(long, env)]
+    pub campaign_id: String,
+    /// URI of the published tree manifest
+    #[clap(long, env)]
+    pub uri: String,
+    /// Folder of the published merkle trees, each distributor records the hash of its own tree file
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
 #[derive(Parser, Debug)]
 pub struct SetEnableSlotArgs {
     /// Merkle tree out path
@@ -429,6 +618,9 @@ fn main() {
         Commands::NewDistributor(new_distributor_args) => {
             process_new_distributor(&args, new_distributor_args);
         }
+        Commands::SetCreators(set_creators_args) => {
+            process_set_creators(&args, set_creators_args);
+        }
         Commands::CloseDistributor(close_distributor_args) => {
             process_close_distributor(&args, close_distributor_args);
         }
@@ -436,15 +628,44 @@ fn main() {
             process_claim(&args, claim_args);
         }
         Commands::Clawback(clawback_args) => process_clawback(&args, clawback_args),
+        Commands::FinalizeDistributor(finalize_args) => {
+            process_finalize_distributor(&args, finalize_args)
+        }
+        Commands::MigrateDistributor(migrate_args) => {
+            process_migrate_distributor(&args, migrate_args)
+        }
+        Commands::Pause(pause_args) => process_set_paused(&args, pause_args, true),
+        Commands::Unpause(pause_args) => process_set_paused(&args, pause_args, false),
+        Commands::WithdrawForgone(withdraw_forgone_args) => {
+            process_withdraw_forgone(&args, withdraw_forgone_args)
+        }
         Commands::CreateMerkleTree(merkle_tree_args) => {
             process_create_merkle_tree(&args, merkle_tree_args);
         }
-        Commands::SetAdmin(set_admin_args) => {
-            process_set_admin(&args, set_admin_args);
+        Commands::ProposeAdmin(propose_admin_args) => {
+            process_propose_admin(&args, propose_admin_args);
+        }
+        Commands::AcceptAdmin(accept_admin_args) => {
+            process_accept_admin(&args, accept_admin_args);
+        }
+        Commands::SetOperator(set_operator_args) => {
+            process_set_operator(&args, set_operator_args);
+        }
+        Commands::SetDepositProgram(set_deposit_program_args) => {
+            process_set_deposit_program(&args, set_deposit_program_args);
+        }
+        Commands::SetClaimFee(set_claim_fee_args) => {
+            process_set_claim_fee(&args, set_claim_fee_args);
+        }
+        Commands::SetDistributorMetadata(set_distributor_metadata_args) => {
+            process_set_distributor_metadata(&args, set_distributor_metadata_args);
         }
         Commands::SetEnableSlot(set_enable_slot_args) => {
             process_set_enable_slot(&args, set_enable_slot_args);
         }
+        Commands::UpdateRoot(update_root_args) => {
+            process_update_root(&args, update_root_args);
+        }
         Commands::SetEnableSlotByTime(set_enable_slot_by_time_args) => {
             process_set_enable_slot_by_time(&args, set_enable_slot_by_time_args);
         }
@@ -472,6 +693,9 @@ fn main() {
         Commands::CloseClaimStatus(_args) => {
             process_close_claim_status(&args);
         }
+        Commands::CloseSettledClaimStatus(close_settled_args) => {
+            process_close_settled_claim_status(&args, close_settled_args);
+        }
         Commands::FilterAndMergeList(filter_and_merge_list_args) => {
             process_filter_and_merge(filter_and_merge_list_args);
         }
@@ -500,7 +724,7 @@ fn check_distributor_onchain_matches(
     pubkey: Pubkey,
     args: &Args,
 ) -> Result<(), &'static str> {
-    if let Ok(distributor) = MerkleDistributor::try_deserialize(&mut account.data.as_slice()) {
+    if let Ok(distributor) = MerkleDistributor::try_deserialize_any_layout(&account.data) {
         if distributor.root != merkle_tree.merkle_root {
             return Err("root mismatch");
         }
@@ -529,6 +753,36 @@ fn check_distributor_onchain_matches(
             return Err("closable mismatch");
         }
 
+        if distributor.start_claim_pct != new_distributor_args.start_claim_pct {
+            return Err("start_claim_pct mismatch");
+        }
+
+        if (distributor.claim_mode == ClaimMode::Bitmap) != merkle_tree.bitmap_claims {
+            return Err("claim_mode mismatch");
+        }
+
+        if distributor.cliff_ts != new_distributor_args.cliff_ts {
+            return Err("cliff_ts mismatch");
+        }
+
+        if distributor.unlock_period != new_distributor_args.unlock_period {
+            return Err("unlock_period mismatch");
+        }
+
+        if distributor.leaf_vesting != merkle_tree.leaf_vesting {
+            return Err("leaf_vesting mismatch");
+        }
+
+        if (distributor.clawback_mode == ClawbackMode::PreserveLocked)
+            != new_distributor_args.preserve_locked_on_clawback
+        {
+            return Err("clawback_mode mismatch");
+        }
+
+        if distributor.claim_end_ts != new_distributor_args.claim_end_ts {
+            return Err("claim_end_ts mismatch");
+        }
+
         // TODO fix code
         let program = args.get_program_client();
         let clawback_receiver_token_account: TokenAccount = program
@@ -598,14 +852,32 @@ fn get_test_list() -> Vec<String> {
     list
 }
 
+/// Returns the token program owning the mint, either SPL Token or Token-2022
+fn get_token_program(rpc_client: &RpcClient, token_mint: &Pubkey) -> Pubkey {
+    rpc_client
+        .get_account(token_mint)
+        .expect("failed to fetch mint account")
+        .owner
+}
+
+/// Reads a [MerkleDistributor] in either account layout, so commands work on distributors not migrated yet.
+fn get_distributor_state<C: Deref<Target = impl Signer> + Clone>(
+    program_client: &anchor_client::Program<C>,
+    distributor: Pubkey,
+) -> Result<MerkleDistributor> {
+    let data = program_client.rpc().get_account_data(&distributor)?;
+    Ok(MerkleDistributor::try_deserialize_any_layout(&data)?)
+}
+
 fn get_or_create_ata<C: Deref<Target = impl Signer> + Clone>(
     program_client: &anchor_client::Program<C>,
     token_mint: Pubkey,
     user: Pubkey,
 ) -> Result<Pubkey> {
-    let user_token_account =
-        spl_associated_token_account::get_associated_token_address(&user, &token_mint);
     let rpc_client = program_client.rpc();
+    let token_program = get_token_program(&rpc_client, &token_mint);
+    let user_token_account =
+        get_associated_token_address_with_program_id(&user, &token_mint, &token_program);
     if rpc_client.get_account_data(&user_token_account).is_err() {
         println!("Create ATA for TOKEN {} \n", &token_mint);
 
@@ -614,7 +886,7 @@ fn get_or_create_ata<C: Deref<Target = impl Signer> + Clone>(
                 &program_client.payer(),
                 &user,
                 &token_mint,
-                &spl_token::ID,
+                &token_program,
             ),
         );
 
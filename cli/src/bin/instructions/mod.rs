@@ -1,19 +1,45 @@
+pub mod process_batch_claim;
+pub use process_batch_claim::*;
 pub mod process_claim;
 pub mod process_new_claim;
 pub use process_claim::*;
 pub use process_new_claim::*;
+pub mod process_new_bitmap_claim;
+pub use process_new_bitmap_claim::*;
 pub mod proccess_close_distribitor;
 pub use proccess_close_distribitor::*;
 pub mod process_new_distributor;
 pub use process_new_distributor::*;
+pub mod process_set_creators;
+pub use process_set_creators::*;
 pub mod process_clawback;
 pub use process_clawback::*;
+pub mod process_finalize_distributor;
+pub use process_finalize_distributor::*;
+pub mod process_migrate_distributor;
+pub use process_migrate_distributor::*;
+pub mod process_set_paused;
+pub use process_set_paused::*;
+pub mod process_withdraw_forgone;
+pub use process_withdraw_forgone::*;
 pub mod process_create_merkle_tree;
 pub use process_create_merkle_tree::*;
-pub mod process_set_admin;
-pub use process_set_admin::*;
+pub mod process_propose_admin;
+pub use process_propose_admin::*;
+pub mod process_accept_admin;
+pub use process_accept_admin::*;
+pub mod process_set_operator;
+pub use process_set_operator::*;
+pub mod process_set_deposit_program;
+pub use process_set_deposit_program::*;
+pub mod process_set_claim_fee;
+pub use process_set_claim_fee::*;
+pub mod process_set_distributor_metadata;
+pub use process_set_distributor_metadata::*;
 pub mod process_set_enable_slot;
 pub use process_set_enable_slot::*;
+pub mod process_update_root;
+pub use process_update_root::*;
 pub mod process_set_enable_slot_by_time;
 pub use process_set_enable_slot_by_time::*;
 pub mod process_create_dummy_csv;
@@ -32,6 +58,8 @@ pub mod process_get_slot;
 pub use process_get_slot::*;
 pub mod process_close_claim_status;
 pub use process_close_claim_status::*;
+pub mod process_close_settled_claim_status;
+pub use process_close_settled_claim_status::*;
 pub mod process_filter_and_merge;
 pub use process_filter_and_merge::*;
 pub mod process_generate_kv_proof;
@@ -20,13 +20,12 @@ pub fn process_close_distributor(args: &Args, close_distributor_args: &CloseDist
             }
         }
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
         let program = args.get_program_client();
         let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
             .expect("Failed reading keypair file");
         // verify distributor is existed
-        let merkle_distributor_state = program.account::<MerkleDistributor>(distributor);
+        let merkle_distributor_state = get_distributor_state(&program, distributor);
         if merkle_distributor_state.is_err() {
             println!("skip version {}", merkle_tree.airdrop_version);
             continue;
@@ -43,7 +42,10 @@ pub fn process_close_distributor(args: &Args, close_distributor_args: &CloseDist
                 token_vault: merkle_distributor_state.token_vault,
                 admin: keypair.pubkey(),
                 destination_token_account,
-                token_program: spl_token::ID,
+                mint: merkle_distributor_state.mint,
+                token_program: get_token_program(&program.rpc(), &merkle_distributor_state.mint),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
             }
             .to_account_metas(None),
             data: merkle_distributor::instruction::CloseDistributor {}.data(),
@@ -0,0 +1,72 @@
+use crate::*;
+
+/// Accepts the admin role on every distributor, signed by the keypair proposed with propose-admin.
+pub fn process_accept_admin(args: &Args, accept_admin_args: &AcceptAdminArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut paths: Vec<_> = fs::read_dir(&accept_admin_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+        let distributor_state = get_distributor_state(&program, distributor).unwrap();
+        if distributor_state.admin == keypair.pubkey() {
+            println!(
+                "already admin skip airdrop version {}",
+                merkle_tree.airdrop_version
+            );
+            continue;
+        }
+        if distributor_state.pending_admin != keypair.pubkey() {
+            println!(
+                "not proposed as admin of airdrop version {}, pending admin is {}",
+                merkle_tree.airdrop_version, distributor_state.pending_admin
+            );
+            continue;
+        }
+
+        let accept_admin_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::AcceptAdmin {
+                distributor,
+                pending_admin: keypair.pubkey(),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::AcceptAdmin {}.data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[accept_admin_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully accepted admin airdrop version {} ! signature: {signature:#?}",
+                    merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use merkle_distributor::{instructions::BatchClaimArgs, state::claim_status::ClaimStatus};
+use solana_program::instruction::AccountMeta;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, packet::PACKET_DATA_SIZE};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::*;
+
+/// Compute units budgeted for each claim of a batch_claim.
+const COMPUTE_UNITS_PER_CLAIM: u32 = 100_000;
+
+/// Claims from every tree in the folder the keypair is in, packing as many claims as fit in each transaction.
+pub fn process_batch_claim(args: &Args, claim_args: &ClaimArgs) {
+    if claim_args.destination.is_some() {
+        panic!("destination is not supported when claiming from a folder of merkle trees");
+    }
+
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let token_program = get_token_program(&client, &args.mint);
+
+    let clock_account = client.get_account(&sysvar::clock::id()).unwrap();
+    let clock = deserialize::<Clock>(&clock_account.data).unwrap();
+
+    let mut paths: Vec<_> = fs::read_dir(&claim_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    let mut claim_fee_receiver = None;
+    let mut claims = vec![];
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let node = match merkle_tree
+            .tree_nodes
+            .iter()
+            .find(|node| node.claimant == claimant)
+        {
+            Some(node) => node,
+            None => continue,
+        };
+        if merkle_tree.bitmap_claims || merkle_tree.leaf_vesting {
+            println!(
+                "skip airdrop version {}, claim it with its own tree file",
+                merkle_tree.airdrop_version
+            );
+            continue;
+        }
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+        let distributor_state = get_distributor_state(&program, distributor).unwrap();
+        let (claim_status_pda, _bump) =
+            get_claim_status_pda(&args.program_id, &claimant, &distributor);
+
+        let claim = match client.get_account_data(&claim_status_pda) {
+            Ok(data) => {
+                // settled claims closed to a tombstone no longer deserialize
+                let claim_status = match ClaimStatus::try_deserialize_any_layout(&data) {
+                    Ok(claim_status) => claim_status,
+                    Err(_) => continue,
+                };
+                let amount = claim_status
+                    .amount_withdrawable(
+                        clock.unix_timestamp,
+                        distributor_state.start_ts,
+                        distributor_state.end_ts,
+                        distributor_state.cliff_ts,
+                        distributor_state.unlock_period,
+                    )
+                    .unwrap_or(0);
+                if amount == 0 {
+                    println!(
+                        "nothing to withdraw from airdrop version {}",
+                        merkle_tree.airdrop_version
+                    );
+                    continue;
+                }
+                BatchClaimArgs {
+                    amount_unlocked: 0,
+                    amount_locked: 0,
+                    proof: vec![],
+                }
+            }
+            // a batch_claim takes a single claim fee receiver
+            Err(_)
+                if distributor_state.has_claim_fee()
+                    && *claim_fee_receiver.get_or_insert(distributor_state.claim_fee_receiver)
+                        != distributor_state.claim_fee_receiver =>
+            {
+                println!(
+                    "skip airdrop version {} with another claim fee receiver, claim it with its own tree file",
+                    merkle_tree.airdrop_version
+                );
+                continue;
+            }
+            Err(_) => BatchClaimArgs {
+                amount_unlocked: node.unlocked_amount(),
+                amount_locked: node.locked_amount(),
+                proof: node.proof.clone().expect("proof not found"),
+            },
+        };
+
+        let vault =
+            get_associated_token_address_with_program_id(&distributor, &args.mint, &token_program);
+        println!(
+            "claiming from airdrop version {} distributor {}",
+            merkle_tree.airdrop_version, distributor
+        );
+        claims.push((
+            vec![
+                AccountMeta::new(distributor, false),
+                AccountMeta::new(claim_status_pda, false),
+                AccountMeta::new(vault, false),
+            ],
+            claim,
+        ));
+    }
+
+    if claims.is_empty() {
+        println!("nothing to claim for {}", claimant);
+        return;
+    }
+
+    // fill each transaction with as many claims as fit, proofs make new claims much larger than withdrawals
+    let mut batch = vec![];
+    for claim in claims {
+        batch.push(claim);
+        let tx = Transaction::new_with_payer(
+            &get_batch_claim_ixs(args, claimant, token_program, claim_fee_receiver, &batch),
+            Some(&claimant),
+        );
+        if batch.len() > 1 && bincode::serialize(&tx).unwrap().len() > PACKET_DATA_SIZE {
+            let next = batch.pop().unwrap();
+            send_batch_claim(
+                args,
+                &client,
+                &keypair,
+                token_program,
+                claim_fee_receiver,
+                &batch,
+            );
+            batch = vec![next];
+        }
+    }
+    send_batch_claim(
+        args,
+        &client,
+        &keypair,
+        token_program,
+        claim_fee_receiver,
+        &batch,
+    );
+}
+
+fn get_batch_claim_ixs(
+    args: &Args,
+    claimant: Pubkey,
+    token_program: Pubkey,
+    claim_fee_receiver: Option<Pubkey>,
+    batch: &[(Vec<AccountMeta>, BatchClaimArgs)],
+) -> Vec<Instruction> {
+    let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+        (COMPUTE_UNITS_PER_CLAIM * batch.len() as u32).min(1_400_000),
+    )];
+
+    let priority_fee = args.priority.unwrap_or(0);
+    if priority_fee > 0 {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    ixs.push(create_associated_token_account_idempotent(
+        &claimant,
+        &claimant,
+        &args.mint,
+        &token_program,
+    ));
+
+    let mut accounts = merkle_distributor::accounts::BatchClaim {
+        to: get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program),
+        claimant,
+        payer: claimant,
+        mint: args.mint,
+        token_program,
+        system_program: solana_program::system_program::ID,
+        claim_fee_receiver,
+        event_authority: get_event_authority_pda(&args.program_id).0,
+        program: args.program_id,
+    }
+    .to_account_metas(None);
+    accounts.extend(batch.iter().flat_map(|(metas, _)| metas.clone()));
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts,
+        data: merkle_distributor::instruction::BatchClaim {
+            claims: batch.iter().map(|(_, claim)| claim.clone()).collect(),
+        }
+        .data(),
+    });
+    ixs
+}
+
+fn send_batch_claim(
+    args: &Args,
+    client: &RpcClient,
+    keypair: &Keypair,
+    token_program: Pubkey,
+    claim_fee_receiver: Option<Pubkey>,
+    batch: &[(Vec<AccountMeta>, BatchClaimArgs)],
+) {
+    let ixs = get_batch_claim_ixs(
+        args,
+        keypair.pubkey(),
+        token_program,
+        claim_fee_receiver,
+        batch,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    match client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(signature) => {
+            println!(
+                "successfully claimed from {} distributors with signature {signature:#?}",
+                batch.len()
+            );
+        }
+        Err(err) => {
+            println!("failed to claim from {} distributors {}", batch.len(), err);
+        }
+    }
+}
@@ -2,15 +2,27 @@ use crate::*;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 
 pub fn process_claim(args: &Args, claim_args: &ClaimArgs) {
+    if claim_args.merkle_tree_path.is_dir() {
+        process_batch_claim(args, claim_args);
+        return;
+    }
+
     let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
         .expect("Failed reading keypair file");
     let claimant = keypair.pubkey();
 
     let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
         .expect("failed to load merkle tree from file");
+    if merkle_tree.bitmap_claims {
+        // bitmap claims are made once, there is nothing locked to withdraw afterwards
+        process_new_bitmap_claim(args, claim_args);
+        return;
+    }
+    if claim_args.destination.is_some() && merkle_tree.leaf_vesting {
+        panic!("destination is not supported for per-leaf vesting");
+    }
 
-    let (distributor, bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+    let (distributor, bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
     println!("distributor pubkey {}", distributor);
 
     let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
@@ -31,7 +43,9 @@ pub fn process_claim(args: &Args, claim_args: &ClaimArgs) {
         }
     }
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let token_program = get_token_program(&client, &args.mint);
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
 
     let mut ixs = vec![];
 
@@ -45,18 +59,54 @@ pub fn process_claim(args: &Args, claim_args: &ClaimArgs) {
         );
     }
 
-    let claim_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::ClaimLocked {
-            distributor,
-            claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
-            to: claimant_ata,
-            claimant,
-            token_program: token::ID,
-        }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::ClaimLocked {}.data(),
+    let from =
+        get_associated_token_address_with_program_id(&distributor, &args.mint, &token_program);
+
+    let claim_ix = match claim_args.destination {
+        Some(destination) => Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::ClaimLockedTo {
+                distributor,
+                claim_status: claim_status_pda,
+                from,
+                to: destination,
+                claimant,
+                mint: args.mint,
+                token_program,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::ClaimLockedTo {}.data(),
+        },
+        None => Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::ClaimLocked {
+                distributor,
+                claim_status: claim_status_pda,
+                from,
+                to: claimant_ata,
+                claimant,
+                mint: args.mint,
+                token_program,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            // the claimant's own schedule is proven again on every withdrawal
+            data: if merkle_tree.leaf_vesting {
+                let node = merkle_tree.get_node(&claimant);
+                let (start_ts, end_ts) = node.vesting().expect("vesting not found");
+                merkle_distributor::instruction::ClaimLockedWithVesting {
+                    start_ts,
+                    end_ts,
+                    proof: node.proof.expect("proof not found"),
+                }
+                .data()
+            } else {
+                merkle_distributor::instruction::ClaimLocked {}.data()
+            },
+        },
     };
 
     ixs.push(claim_ix);
@@ -21,11 +21,10 @@ pub fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         loop {
-            let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+            let distributor_state = get_distributor_state(&program, distributor).unwrap();
             if distributor_state.clawed_back {
                 println!("already clawback {}", merkle_tree.airdrop_version);
                 break;
@@ -35,10 +34,13 @@ pub fn process_clawback(args: &Args, clawback_args: &ClawbackArgs) {
                 accounts: merkle_distributor::accounts::Clawback {
                     distributor,
                     from: distributor_state.token_vault,
-                    token_program: spl_token::ID,
+                    mint: distributor_state.mint,
+                    token_program: get_token_program(&client, &distributor_state.mint),
                     to: distributor_state.clawback_receiver,
                     claimant: keypair.pubkey(),
                     system_program: system_program::ID,
+                    event_authority: get_event_authority_pda(&args.program_id).0,
+                    program: args.program_id,
                 }
                 .to_account_metas(None),
                 data: merkle_distributor::instruction::Clawback {}.data(),
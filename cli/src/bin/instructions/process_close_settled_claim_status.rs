@@ -0,0 +1,66 @@
+use merkle_distributor::state::claim_status::ClaimStatus;
+
+use crate::*;
+
+/// Closes the keypair's ClaimStatus once all its locked tokens are withdrawn, reclaiming the rent.
+pub fn process_close_settled_claim_status(
+    args: &Args,
+    close_settled_args: &CloseSettledClaimStatusArgs,
+) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&close_settled_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+    let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let claim_status = match program.account::<ClaimStatus>(claim_status_pda) {
+        Ok(claim_status) => claim_status,
+        Err(e) => {
+            println!("no open claim status {claim_status_pda}: {e}");
+            return;
+        }
+    };
+    if !claim_status.is_settled() {
+        println!(
+            "{} locked tokens left to withdraw, claim them first",
+            claim_status.locked_amount - claim_status.locked_amount_withdrawn
+        );
+        return;
+    }
+    if claim_status.rent_sponsored {
+        println!("claim status rent was paid by a sponsor, nothing to reclaim");
+        return;
+    }
+
+    let close_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::CloseSettledClaimStatus {
+            distributor,
+            claim_status: claim_status_pda,
+            claimant,
+            event_authority: get_event_authority_pda(&args.program_id).0,
+            program: args.program_id,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::CloseSettledClaimStatus {}.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&claimant),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully closed claim status with signature {signature:#?}",);
+}
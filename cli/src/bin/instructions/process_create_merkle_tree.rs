@@ -16,8 +16,7 @@ pub fn process_create_merkle_tree(args: &Args, merkle_tree_args: &CreateMerkleTr
         
         let mut current_version = 0;
         while current_version < 1000000000000000000 {
-            let (distributor_pubkey, _bump) =
-                get_merkle_distributor_pda(&args.program_id, &args.mint, current_version);
+            let (distributor_pubkey, _bump) = args.get_distributor_pda(current_version);
             match rpc_client.get_account_data(&distributor_pubkey) {
                 Ok(_) => {
                     println!("Airdrop version {} exists", current_version);
@@ -73,8 +72,13 @@ pub fn process_create_merkle_tree(args: &Args, merkle_tree_args: &CreateMerkleTr
 
         // use airdrop_version as version
         let merkle_tree =
-            AirdropMerkleTree::new_from_entries(sub_tree, airdrop_version, merkle_tree_args.decimals)
-                .unwrap();
+            AirdropMerkleTree::new_from_entries(
+                sub_tree,
+                airdrop_version,
+                merkle_tree_args.decimals,
+                merkle_tree_args.bitmap_claims,
+            )
+            .unwrap();
 
         let base_path_clone = base_path.clone();
         let path = base_path_clone
@@ -93,12 +97,19 @@ pub fn process_create_merkle_tree(args: &Args, merkle_tree_args: &CreateMerkleTr
                 pubkey: x,
                 amount: merkle_tree_args.amount,
                 locked_amount: Some(0),
+                start_ts: None,
+                end_ts: None,
             })
             .collect::<Vec<CsvEntry>>();
 
         let merkle_tree =
-            AirdropMerkleTree::new_from_entries(test_list, airdrop_version, merkle_tree_args.decimals as u32)
-                .unwrap();
+            AirdropMerkleTree::new_from_entries(
+                test_list,
+                airdrop_version,
+                merkle_tree_args.decimals as u32,
+                merkle_tree_args.bitmap_claims,
+            )
+            .unwrap();
         let base_path_clone = base_path.clone();
         let path = base_path_clone
             .as_path()
@@ -0,0 +1,79 @@
+use crate::*;
+
+/// Closes every clawed back distributor with its vault and metadata, sending any dust to the clawback receiver.
+pub fn process_finalize_distributor(args: &Args, finalize_args: &FinalizeDistributorArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut paths: Vec<_> = fs::read_dir(&finalize_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+        let distributor_state = match get_distributor_state(&program, distributor) {
+            Ok(distributor_state) => distributor_state,
+            Err(_) => {
+                println!("already finalized {}", merkle_tree.airdrop_version);
+                continue;
+            }
+        };
+        if !distributor_state.clawed_back {
+            println!(
+                "not clawed back yet, skip airdrop version {}",
+                merkle_tree.airdrop_version
+            );
+            continue;
+        }
+
+        let (metadata, _bump) = get_distributor_metadata_pda(&args.program_id, &distributor);
+        let metadata = client.get_account(&metadata).ok().map(|_| metadata);
+
+        let finalize_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::FinalizeDistributor {
+                distributor,
+                from: distributor_state.token_vault,
+                to: distributor_state.clawback_receiver,
+                admin: keypair.pubkey(),
+                mint: distributor_state.mint,
+                token_program: get_token_program(&client, &distributor_state.mint),
+                metadata,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::FinalizeDistributor {}.data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[finalize_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully finalized airdrop version {} ! signature: {signature:#?}",
+                    merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+}
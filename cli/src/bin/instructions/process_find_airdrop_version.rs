@@ -10,8 +10,7 @@ pub fn process_find_airdrop_version(args: &Args, new_distributor_args: &FindAird
     println!("Starting to find airdrop version for mint: {}, starting from: {}", args.mint, start_version_check);
 
     while current_version < 1000000000000000000 {
-        let (distributor_pubkey, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, current_version);
+        let (distributor_pubkey, _bump) = args.get_distributor_pda(current_version);
         match rpc_client.get_account_data(&distributor_pubkey) {
             Ok(_) => {
                 println!("Airdrop version {} exists, account: {}", current_version, distributor_pubkey);
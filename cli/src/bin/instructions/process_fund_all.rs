@@ -12,7 +12,10 @@ pub fn process_fund_all(args: &Args, fund_all_args: &FundAllArgs) {
         .collect();
     paths.sort_by_key(|dir| dir.path());
 
-    let source_vault = get_associated_token_address(&keypair.pubkey(), &args.mint);
+    let token_program = get_token_program(&client, &args.mint);
+    let mint: Mint = program.account(args.mint).unwrap();
+    let source_vault =
+        get_associated_token_address_with_program_id(&keypair.pubkey(), &args.mint, &token_program);
 
     for file in paths {
         let single_tree_path = file.path();
@@ -42,10 +45,13 @@ pub fn process_fund_all(args: &Args, fund_all_args: &FundAllArgs) {
 
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
-        let (distributor_pubkey, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor_pubkey, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
-        let token_vault = get_associated_token_address(&distributor_pubkey, &args.mint);
+        let token_vault = get_associated_token_address_with_program_id(
+            &distributor_pubkey,
+            &args.mint,
+            &token_program,
+        );
 
         let token_vault_state: TokenAccount = program.account(token_vault).unwrap();
         if token_vault_state.amount >= merkle_tree.max_total_claim {
@@ -69,13 +75,15 @@ pub fn process_fund_all(args: &Args, fund_all_args: &FundAllArgs) {
         }
 
         ixs.push(
-            spl_token::instruction::transfer(
-                &spl_token::id(),
+            anchor_spl::token_2022::spl_token_2022::instruction::transfer_checked(
+                &token_program,
                 &source_vault,
+                &args.mint,
                 &token_vault,
                 &keypair.pubkey(),
                 &[],
                 merkle_tree.max_total_claim,
+                mint.decimals,
             )
             .unwrap(),
         );
@@ -10,6 +10,9 @@ pub struct KvProof {
     pub merkle_tree: String,
     pub amount: u64,
     pub locked_amount: Option<u64>,
+    pub leaf_index: Option<u64>,
+    pub start_ts: Option<i64>,
+    pub end_ts: Option<i64>,
     /// Claimant's proof of inclusion in the Merkle Tree
     pub proof: Vec<[u8; 32]>,
 }
@@ -30,8 +33,7 @@ pub fn process_generate_kv_proof(args: &Args, generate_kv_proof_args: &GenerateK
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
 
-        let (distributor_pubkey, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor_pubkey, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         for node in merkle_tree.tree_nodes.iter() {
             let user_pk = Pubkey::from(node.claimant);
@@ -41,6 +43,9 @@ pub fn process_generate_kv_proof(args: &Args, generate_kv_proof_args: &GenerateK
                     merkle_tree: distributor_pubkey.to_string(),
                     amount: node.amount,
                     locked_amount: node.locked_amount,
+                    leaf_index: node.leaf_index,
+                    start_ts: node.start_ts,
+                    end_ts: node.end_ts,
                     proof: node.proof.clone().unwrap(),
                 },
             );
@@ -0,0 +1,72 @@
+use anchor_lang::system_program;
+
+use crate::*;
+
+pub fn process_migrate_distributor(args: &Args, migrate_args: &MigrateDistributorArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut paths: Vec<_> = fs::read_dir(&migrate_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+        let distributor_data = match client.get_account_data(&distributor) {
+            Ok(data) => data,
+            Err(_) => {
+                println!(
+                    "distributor not found, skip airdrop version {}",
+                    merkle_tree.airdrop_version
+                );
+                continue;
+            }
+        };
+        if distributor_data.len() >= MerkleDistributor::LEN {
+            println!("already migrated {}", merkle_tree.airdrop_version);
+            continue;
+        }
+
+        let migrate_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::MigrateDistributor {
+                distributor,
+                payer: keypair.pubkey(),
+                system_program: system_program::ID,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::MigrateDistributor {}.data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[migrate_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully migrated airdrop version {} ! signature: {signature:#?}",
+                    merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use merkle_distributor::state::claim_bitmap::ClaimBitmap;
+
+use crate::*;
+
+pub fn process_new_bitmap_claim(args: &Args, claim_args: &ClaimArgs) {
+    if claim_args.destination.is_some() {
+        panic!("destination is not supported for bitmap claims");
+    }
+
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    println!("Claiming tokens for user {}...", claimant);
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+    // Get user's node in claim
+    let node = merkle_tree.get_node(&claimant);
+    let leaf_index = node.leaf_index.expect("leaf index not found");
+
+    let (claim_bitmap, _bump) = get_claim_bitmap_pda(
+        &args.program_id,
+        &distributor,
+        ClaimBitmap::bitmap_index(leaf_index),
+    );
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let token_program = get_token_program(&client, &args.mint);
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+
+    let mut ixs = vec![];
+
+    match client.get_account(&claimant_ata) {
+        Ok(_) => {}
+        Err(e) => {
+            // TODO: directly pattern match on error kind
+            if e.to_string().contains("AccountNotFound") {
+                println!("PDA does not exist. creating.");
+                let ix = create_associated_token_account(
+                    &claimant,
+                    &claimant,
+                    &args.mint,
+                    &token_program,
+                );
+                ixs.push(ix);
+            } else {
+                panic!("Error fetching PDA: {e}")
+            }
+        }
+    }
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::NewBitmapClaim {
+            distributor,
+            claim_bitmap,
+            from: get_associated_token_address_with_program_id(
+                &distributor,
+                &args.mint,
+                &token_program,
+            ),
+            to: claimant_ata,
+            claimant,
+            mint: args.mint,
+            token_program,
+            event_authority: get_event_authority_pda(&args.program_id).0,
+            program: args.program_id,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::NewBitmapClaim {
+            leaf_index,
+            amount_unlocked: node.unlocked_amount(),
+            proof: node.proof.expect("proof not found"),
+        }
+        .data(),
+    });
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&claimant.key()), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed leaf {leaf_index} with signature {signature:#?}");
+}
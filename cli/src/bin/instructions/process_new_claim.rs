@@ -9,53 +9,127 @@ pub fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
     let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
         .expect("failed to load merkle tree from file");
 
-    let (distributor, _bump) =
-        get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+    let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
     // Get user's node in claim
     let node = merkle_tree.get_node(&claimant);
+    if claim_args.destination.is_some() && merkle_tree.leaf_vesting {
+        panic!("destination is not supported for per-leaf vesting");
+    }
 
     let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let distributor_state = get_distributor_state(&program, distributor).unwrap();
+    if distributor_state.has_claim_fee() {
+        println!(
+            "Paying a claim fee of {} lamports and {} bps to {}",
+            distributor_state.claim_fee_lamports,
+            distributor_state.claim_fee_bps,
+            distributor_state.claim_fee_receiver
+        );
+    }
+
+    let token_program = get_token_program(&client, &args.mint);
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let from =
+        get_associated_token_address_with_program_id(&distributor, &args.mint, &token_program);
 
     let mut ixs = vec![];
 
-    match client.get_account(&claimant_ata) {
-        Ok(_) => {}
-        Err(e) => {
-            // TODO: directly pattern match on error kind
-            if e.to_string().contains("AccountNotFound") {
-                println!("PDA does not exist. creating.");
-                let ix =
-                    create_associated_token_account(&claimant, &claimant, &args.mint, &token::ID);
-                ixs.push(ix);
-            } else {
-                panic!("Error fetching PDA: {e}")
+    let new_claim_ix = match claim_args.destination {
+        Some(destination) => {
+            println!("Sending claimed tokens to {}", destination);
+            Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::NewClaimTo {
+                    distributor,
+                    claim_status: claim_status_pda,
+                    from,
+                    to: destination,
+                    claimant,
+                    payer: claimant,
+                    mint: args.mint,
+                    token_program,
+                    system_program: solana_program::system_program::ID,
+                    claim_fee_receiver: distributor_state
+                        .has_claim_fee()
+                        .then_some(distributor_state.claim_fee_receiver),
+                    event_authority: get_event_authority_pda(&args.program_id).0,
+                    program: args.program_id,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::NewClaimTo {
+                    amount_unlocked: node.unlocked_amount(),
+                    amount_locked: node.locked_amount(),
+                    proof: node.proof.expect("proof not found"),
+                }
+                .data(),
             }
         }
-    }
+        None => {
+            match client.get_account(&claimant_ata) {
+                Ok(_) => {}
+                Err(e) => {
+                    // TODO: directly pattern match on error kind
+                    if e.to_string().contains("AccountNotFound") {
+                        println!("PDA does not exist. creating.");
+                        let ix = create_associated_token_account(
+                            &claimant,
+                            &claimant,
+                            &args.mint,
+                            &token_program,
+                        );
+                        ixs.push(ix);
+                    } else {
+                        panic!("Error fetching PDA: {e}")
+                    }
+                }
+            }
 
-    let new_claim_ix = Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::NewClaim {
-            distributor,
-            claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
-            to: claimant_ata,
-            claimant,
-            token_program: token::ID,
-            system_program: solana_program::system_program::ID,
-        }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::NewClaim {
-            amount_unlocked: node.unlocked_amount(),
-            amount_locked: node.locked_amount(),
-            proof: node.proof.expect("proof not found"),
+            Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::NewClaim {
+                    distributor,
+                    claim_status: claim_status_pda,
+                    from,
+                    to: claimant_ata,
+                    claimant,
+                    payer: claimant,
+                    mint: args.mint,
+                    token_program,
+                    system_program: solana_program::system_program::ID,
+                    claim_fee_receiver: distributor_state
+                        .has_claim_fee()
+                        .then_some(distributor_state.claim_fee_receiver),
+                    event_authority: get_event_authority_pda(&args.program_id).0,
+                    program: args.program_id,
+                }
+                .to_account_metas(None),
+                data: match node.vesting() {
+                    Some((start_ts, end_ts)) => {
+                        merkle_distributor::instruction::NewClaimWithVesting {
+                            amount_unlocked: node.unlocked_amount(),
+                            amount_locked: node.locked_amount(),
+                            start_ts,
+                            end_ts,
+                            proof: node.proof.expect("proof not found"),
+                        }
+                        .data()
+                    }
+                    None => merkle_distributor::instruction::NewClaim {
+                        amount_unlocked: node.unlocked_amount(),
+                        amount_locked: node.locked_amount(),
+                        proof: node.proof.expect("proof not found"),
+                    }
+                    .data(),
+                },
+            }
         }
-        .data(),
     };
 
     ixs.push(new_claim_ix);
@@ -1,4 +1,5 @@
 use crate::*;
+use merkle_distributor::state::claim_bitmap::LEAVES_PER_CLAIM_BITMAP;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 
 pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs) {
@@ -14,6 +15,7 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
         .collect();
     paths.sort_by_key(|dir| dir.path());
     let program = args.get_program_client();
+    let token_program = get_token_program(&client, &args.mint);
 
     for file in paths {
         let single_tree_path = file.path();
@@ -35,8 +37,13 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
                 continue;
             }
         }
-        let (distributor_pubkey, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        // new distributors are always derived from their admin, whatever --base is
+        let (distributor_pubkey, _bump) = get_merkle_distributor_pda(
+            &args.program_id,
+            &keypair.pubkey(),
+            &args.mint,
+            merkle_tree.airdrop_version,
+        );
 
         if let Some(account) = client
             .get_account_with_commitment(&distributor_pubkey, CommitmentConfig::confirmed())
@@ -54,6 +61,9 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
                 keypair.pubkey(),
                 &args,
             ).expect("merkle root on-chain does not match provided arguments! Confirm admin and clawback parameters to avoid loss of funds!");
+            if merkle_tree.bitmap_claims {
+                init_claim_bitmaps(args, &client, &keypair, distributor_pubkey, &merkle_tree);
+            }
             continue;
         }
 
@@ -69,9 +79,10 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
             );
         }
 
-        let token_vault = spl_associated_token_account::get_associated_token_address(
+        let token_vault = get_associated_token_address_with_program_id(
             &distributor_pubkey,
             &args.mint,
+            &token_program,
         );
         if client.get_account_data(&token_vault).is_err() {
             ixs.push(
@@ -79,7 +90,7 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
                     &keypair.pubkey(),
                     &distributor_pubkey,
                     &args.mint,
-                    &spl_token::ID,
+                    &token_program,
                 ),
             );
         }
@@ -98,11 +109,21 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
                 clawback_receiver,
                 mint: args.mint,
                 token_vault,
+                funder: new_distributor_args.fund.then(|| {
+                    get_associated_token_address_with_program_id(
+                        &keypair.pubkey(),
+                        &args.mint,
+                        &token_program,
+                    )
+                }),
                 distributor: distributor_pubkey,
                 system_program: solana_program::system_program::id(),
                 associated_token_program: spl_associated_token_account::ID,
-                token_program: token::ID,
+                token_program,
                 admin: keypair.pubkey(),
+                program_config: get_program_config_pda(&args.program_id).0,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
             }
             .to_account_metas(None),
             data: merkle_distributor::instruction::NewDistributor {
@@ -115,6 +136,21 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
                 clawback_start_ts: new_distributor_args.clawback_start_ts,
                 enable_slot: new_distributor_args.enable_slot,
                 closable: new_distributor_args.closable,
+                start_claim_pct: new_distributor_args.start_claim_pct,
+                claim_mode: if merkle_tree.bitmap_claims {
+                    ClaimMode::Bitmap
+                } else {
+                    ClaimMode::ClaimStatus
+                },
+                cliff_ts: new_distributor_args.cliff_ts,
+                unlock_period: new_distributor_args.unlock_period,
+                leaf_vesting: merkle_tree.leaf_vesting,
+                clawback_mode: if new_distributor_args.preserve_locked_on_clawback {
+                    ClawbackMode::PreserveLocked
+                } else {
+                    ClawbackMode::Full
+                },
+                claim_end_ts: new_distributor_args.claim_end_ts,
             }
             .data(),
         });
@@ -174,6 +210,10 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
             }
         }
 
+        if merkle_tree.bitmap_claims {
+            init_claim_bitmaps(args, &client, &keypair, distributor_pubkey, &merkle_tree);
+        }
+
         if new_distributor_args.airdrop_version.is_some() {
             let airdrop_version = new_distributor_args.airdrop_version.unwrap();
             if airdrop_version == merkle_tree.airdrop_version {
@@ -182,3 +222,55 @@ pub fn process_new_distributor(args: &Args, new_distributor_args: &NewDistributo
         }
     }
 }
+
+/// Creates the claim bitmaps covering every leaf of a bitmap mode distributor, skipping existing ones
+pub fn init_claim_bitmaps(
+    args: &Args,
+    client: &RpcClient,
+    keypair: &Keypair,
+    distributor: Pubkey,
+    merkle_tree: &AirdropMerkleTree,
+) {
+    let num_bitmaps =
+        (merkle_tree.max_num_nodes + LEAVES_PER_CLAIM_BITMAP - 1) / LEAVES_PER_CLAIM_BITMAP;
+    for bitmap_index in 0..num_bitmaps {
+        let (claim_bitmap, _bump) =
+            get_claim_bitmap_pda(&args.program_id, &distributor, bitmap_index);
+        if client.get_account_data(&claim_bitmap).is_ok() {
+            continue;
+        }
+
+        let ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::InitClaimBitmap {
+                distributor,
+                claim_bitmap,
+                admin: keypair.pubkey(),
+                system_program: solana_program::system_program::id(),
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::InitClaimBitmap { bitmap_index }.data(),
+        };
+
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            blockhash,
+        );
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(_) => {
+                println!(
+                    "done create claim bitmap {} for merkle distributor version {} {:?}",
+                    bitmap_index,
+                    merkle_tree.airdrop_version,
+                    tx.get_signature(),
+                );
+            }
+            Err(e) => {
+                println!("Failed to create ClaimBitmap {}: {:?}", bitmap_index, e);
+            }
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use crate::*;
 
-pub fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
+pub fn process_propose_admin(args: &Args, propose_admin_args: &ProposeAdminArgs) {
     let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
         .expect("Failed reading keypair file");
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
     let program = args.get_program_client();
 
-    let mut paths: Vec<_> = fs::read_dir(&set_admin_args.merkle_tree_path)
+    let mut paths: Vec<_> = fs::read_dir(&propose_admin_args.merkle_tree_path)
         .unwrap()
         .map(|r| r.unwrap())
         .collect();
@@ -19,31 +19,34 @@ pub fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         loop {
-            let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
-            if distributor_state.admin == set_admin_args.new_admin {
+            let distributor_state = get_distributor_state(&program, distributor).unwrap();
+            if distributor_state.admin == propose_admin_args.new_admin
+                || distributor_state.pending_admin == propose_admin_args.new_admin
+            {
                 println!(
-                    "already the same skip airdrop version {}",
+                    "already proposed skip airdrop version {}",
                     merkle_tree.airdrop_version
                 );
                 break;
             }
-            let set_admin_ix = Instruction {
+            let propose_admin_ix = Instruction {
                 program_id: args.program_id,
-                accounts: merkle_distributor::accounts::SetAdmin {
+                accounts: merkle_distributor::accounts::ProposeAdmin {
                     distributor,
                     admin: keypair.pubkey(),
-                    new_admin: set_admin_args.new_admin,
+                    new_admin: propose_admin_args.new_admin,
+                    event_authority: get_event_authority_pda(&args.program_id).0,
+                    program: args.program_id,
                 }
                 .to_account_metas(None),
-                data: merkle_distributor::instruction::SetAdmin {}.data(),
+                data: merkle_distributor::instruction::ProposeAdmin {}.data(),
             };
 
             let tx = Transaction::new_signed_with_payer(
-                &[set_admin_ix],
+                &[propose_admin_ix],
                 Some(&keypair.pubkey()),
                 &[&keypair],
                 client.get_latest_blockhash().unwrap(),
@@ -52,8 +55,8 @@ pub fn process_set_admin(args: &Args, set_admin_args: &SetAdminArgs) {
             match client.send_transaction(&tx) {
                 Ok(signature) => {
                     println!(
-                        "Successfully set admin {} airdrop version {} ! signature: {signature:#?}",
-                        set_admin_args.new_admin, merkle_tree.airdrop_version
+                        "Successfully proposed admin {} airdrop version {} ! signature: {signature:#?}",
+                        propose_admin_args.new_admin, merkle_tree.airdrop_version
                     );
                     break;
                 }
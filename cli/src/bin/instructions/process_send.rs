@@ -27,7 +27,10 @@ fn send_for_batch_address(
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::finalized());
     let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
         .expect("Failed reading keypair file");
-    let source_vault = get_associated_token_address(&keypair.pubkey(), &args.mint);
+    let token_program = get_token_program(&client, &args.mint);
+    let mint: Mint = args.get_program_client().account(args.mint).unwrap();
+    let source_vault =
+        get_associated_token_address_with_program_id(&keypair.pubkey(), &args.mint, &token_program);
     let mut mass_ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_000_000)];
 
     let mut qualified_address = vec![];
@@ -36,7 +39,7 @@ fn send_for_batch_address(
             Ok(user) => {
                 qualified_address.push(address.clone());
                 let user_ata =
-                    spl_associated_token_account::get_associated_token_address(&user, &args.mint);
+                    get_associated_token_address_with_program_id(&user, &args.mint, &token_program);
 
                 if client.get_account_data(&user_ata).is_err() {
                     mass_ixs.push(
@@ -44,19 +47,21 @@ fn send_for_batch_address(
                             &keypair.pubkey(),
                             &user,
                             &args.mint,
-                            &spl_token::ID,
+                            &token_program,
                         ),
                     );
                 }
 
                 mass_ixs.push(
-                    spl_token::instruction::transfer(
-                        &spl_token::id(),
+                    anchor_spl::token_2022::spl_token_2022::instruction::transfer_checked(
+                        &token_program,
                         &source_vault,
+                        &args.mint,
                         &user_ata,
                         &keypair.pubkey(),
                         &[],
                         amount,
+                        mint.decimals,
                     )
                     .unwrap(),
                 )
@@ -0,0 +1,85 @@
+use crate::*;
+
+pub fn process_set_claim_fee(args: &Args, set_claim_fee_args: &SetClaimFeeArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let claim_fee_receiver = match set_claim_fee_args.claim_fee_receiver {
+        Some(claim_fee_receiver) => claim_fee_receiver,
+        None if set_claim_fee_args.claim_fee_lamports == 0
+            && set_claim_fee_args.claim_fee_bps == 0 =>
+        {
+            Pubkey::default()
+        }
+        None => panic!("claim fee receiver is required to charge a claim fee"),
+    };
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut paths: Vec<_> = fs::read_dir(&set_claim_fee_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+        let distributor_state = get_distributor_state(&program, distributor).unwrap();
+        if distributor_state.claim_fee_lamports == set_claim_fee_args.claim_fee_lamports
+            && distributor_state.claim_fee_bps == set_claim_fee_args.claim_fee_bps
+            && (!distributor_state.has_claim_fee()
+                || distributor_state.claim_fee_receiver == claim_fee_receiver)
+        {
+            println!(
+                "already the same skip airdrop version {}",
+                merkle_tree.airdrop_version
+            );
+            continue;
+        }
+
+        let set_claim_fee_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::SetClaimFee {
+                distributor,
+                admin: keypair.pubkey(),
+                new_claim_fee_receiver: claim_fee_receiver,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::SetClaimFee {
+                claim_fee_lamports: set_claim_fee_args.claim_fee_lamports,
+                claim_fee_bps: set_claim_fee_args.claim_fee_bps,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[set_claim_fee_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully set claim fee of {} lamports and {} bps airdrop version {} ! signature: {signature:#?}",
+                    set_claim_fee_args.claim_fee_lamports,
+                    set_claim_fee_args.claim_fee_bps,
+                    merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+}
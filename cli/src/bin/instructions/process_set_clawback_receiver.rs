@@ -16,9 +16,10 @@ pub fn process_set_clawback_receiver(
         .collect();
     paths.sort_by_key(|dir| dir.path());
 
-    let new_clawback_account = spl_associated_token_account::get_associated_token_address(
+    let new_clawback_account = get_associated_token_address_with_program_id(
         &set_clawback_receiver_args.receiver,
         &args.mint,
+        &get_token_program(&client, &args.mint),
     );
 
     for file in paths {
@@ -27,11 +28,10 @@ pub fn process_set_clawback_receiver(
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         loop {
-            let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+            let distributor_state = get_distributor_state(&program, distributor).unwrap();
             if distributor_state.clawback_receiver == new_clawback_account {
                 println!(
                     "already the same skip airdrop version {}",
@@ -45,6 +45,8 @@ pub fn process_set_clawback_receiver(
                     distributor,
                     admin: keypair.pubkey(),
                     new_clawback_account,
+                    event_authority: get_event_authority_pda(&args.program_id).0,
+                    program: args.program_id,
                 }
                 .to_account_metas(None),
                 data: merkle_distributor::instruction::SetClawbackReceiver {}.data(),
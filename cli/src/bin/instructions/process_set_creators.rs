@@ -0,0 +1,67 @@
+use solana_program::bpf_loader_upgradeable::get_program_data_address;
+
+use crate::*;
+
+pub fn process_set_creators(args: &Args, set_creators_args: &SetCreatorsArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let (program_config, _bump) = get_program_config_pda(&args.program_id);
+    let program_data = get_program_data_address(&args.program_id);
+    let creators = set_creators_args.creators.clone();
+
+    // the config is created by the first call
+    let set_creators_ix = if client.get_account(&program_config).is_err() {
+        println!(
+            "program config {} does not exist. creating.",
+            program_config
+        );
+        Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::InitProgramConfig {
+                program_config,
+                program_data,
+                authority: keypair.pubkey(),
+                system_program: solana_program::system_program::ID,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::InitProgramConfig { creators }.data(),
+        }
+    } else {
+        Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::SetCreators {
+                program_config,
+                program_data,
+                authority: keypair.pubkey(),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::SetCreators { creators }.data(),
+        }
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_creators_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    match client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(signature) => {
+            println!(
+                "Successfully set creators {:?} ! signature: {signature:#?}",
+                set_creators_args.creators
+            );
+        }
+        Err(err) => {
+            println!("failed to set creators {}", err);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::{system_program, AccountDeserialize};
+use merkle_distributor::state::distributor_metadata::DistributorMetadata;
+use solana_program::hash::hash;
+
+use crate::*;
+
+pub fn process_set_distributor_metadata(
+    args: &Args,
+    set_distributor_metadata_args: &SetDistributorMetadataArgs,
+) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut paths: Vec<_> = fs::read_dir(&set_distributor_metadata_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+        let content_hash = hash(&fs::read(&single_tree_path).unwrap()).to_bytes();
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+        let (metadata, _bump) = get_distributor_metadata_pda(&args.program_id, &distributor);
+
+        if let Ok(data) = client.get_account_data(&metadata) {
+            let metadata_state =
+                DistributorMetadata::try_deserialize(&mut data.as_slice()).unwrap();
+            if metadata_state.name == set_distributor_metadata_args.name
+                && metadata_state.campaign_id == set_distributor_metadata_args.campaign_id
+                && metadata_state.uri == set_distributor_metadata_args.uri
+                && metadata_state.content_hash == content_hash
+            {
+                println!(
+                    "already the same skip airdrop version {}",
+                    merkle_tree.airdrop_version
+                );
+                continue;
+            }
+        }
+
+        let set_distributor_metadata_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::SetDistributorMetadata {
+                distributor,
+                metadata,
+                admin: keypair.pubkey(),
+                system_program: system_program::ID,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::SetDistributorMetadata {
+                name: set_distributor_metadata_args.name.clone(),
+                campaign_id: set_distributor_metadata_args.campaign_id.clone(),
+                uri: set_distributor_metadata_args.uri.clone(),
+                content_hash,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[set_distributor_metadata_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully set metadata airdrop version {} ! signature: {signature:#?}",
+                    merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+}
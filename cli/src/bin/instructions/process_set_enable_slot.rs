@@ -12,9 +12,8 @@ pub fn process_set_enable_slot(args: &Args, set_enable_slot_args: &SetEnableSlot
     if set_enable_slot_args.airdrop_version.is_some() {
         let airdrop_version = set_enable_slot_args.airdrop_version.unwrap();
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, airdrop_version);
-        let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+        let (distributor, _bump) = args.get_distributor_pda(airdrop_version);
+        let distributor_state = get_distributor_state(&program, distributor).unwrap();
         if distributor_state.enable_slot == set_enable_slot_args.slot {
             println!("already set slot skip airdrop version {}", airdrop_version);
             return;
@@ -23,7 +22,9 @@ pub fn process_set_enable_slot(args: &Args, set_enable_slot_args: &SetEnableSlot
             program_id: args.program_id,
             accounts: merkle_distributor::accounts::SetEnableSlot {
                 distributor,
-                admin: keypair.pubkey(),
+                authority: keypair.pubkey(),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
             }
             .to_account_metas(None),
             data: merkle_distributor::instruction::SetEnableSlot {
@@ -62,11 +63,10 @@ pub fn process_set_enable_slot(args: &Args, set_enable_slot_args: &SetEnableSlot
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         loop {
-            let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+            let distributor_state = get_distributor_state(&program, distributor).unwrap();
             if distributor_state.enable_slot == set_enable_slot_args.slot {
                 println!(
                     "already set slot skip airdrop version {}",
@@ -78,7 +78,9 @@ pub fn process_set_enable_slot(args: &Args, set_enable_slot_args: &SetEnableSlot
                 program_id: args.program_id,
                 accounts: merkle_distributor::accounts::SetEnableSlot {
                     distributor,
-                    admin: keypair.pubkey(),
+                    authority: keypair.pubkey(),
+                    event_authority: get_event_authority_pda(&args.program_id).0,
+                    program: args.program_id,
                 }
                 .to_account_metas(None),
                 data: merkle_distributor::instruction::SetEnableSlot {
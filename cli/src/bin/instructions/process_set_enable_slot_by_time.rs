@@ -37,14 +37,15 @@ pub fn process_set_enable_slot_by_time(
 
     if set_enable_slot_by_time_args.airdrop_version.is_some() {
         let airdrop_version = set_enable_slot_by_time_args.airdrop_version.unwrap();
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(airdrop_version);
 
         let set_slot_ix = Instruction {
             program_id: args.program_id,
             accounts: merkle_distributor::accounts::SetEnableSlot {
                 distributor,
-                admin: keypair.pubkey(),
+                authority: keypair.pubkey(),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
             }
             .to_account_metas(None),
             data: merkle_distributor::instruction::SetEnableSlot { enable_slot: slot }.data(),
@@ -74,14 +75,15 @@ pub fn process_set_enable_slot_by_time(
         let merkle_tree =
             AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
 
-        let (distributor, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         let set_slot_ix = Instruction {
             program_id: args.program_id,
             accounts: merkle_distributor::accounts::SetEnableSlot {
                 distributor,
-                admin: keypair.pubkey(),
+                authority: keypair.pubkey(),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
             }
             .to_account_metas(None),
             data: merkle_distributor::instruction::SetEnableSlot { enable_slot: slot }.data(),
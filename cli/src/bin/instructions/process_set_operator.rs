@@ -0,0 +1,65 @@
+use crate::*;
+
+pub fn process_set_operator(args: &Args, set_operator_args: &SetOperatorArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut paths: Vec<_> = fs::read_dir(&set_operator_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+        let distributor_state = get_distributor_state(&program, distributor).unwrap();
+        if distributor_state.operator == set_operator_args.operator {
+            println!(
+                "already the same skip airdrop version {}",
+                merkle_tree.airdrop_version
+            );
+            continue;
+        }
+
+        let set_operator_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::SetOperator {
+                distributor,
+                admin: keypair.pubkey(),
+                new_operator: set_operator_args.operator,
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::SetOperator {}.data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[set_operator_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully set operator {} airdrop version {} ! signature: {signature:#?}",
+                    set_operator_args.operator, merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::*;
+
+pub fn process_update_root(args: &Args, update_root_args: &UpdateRootArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&update_root_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+    let distributor_state = get_distributor_state(&program, distributor).unwrap();
+
+    // the leaf schema is fixed at creation, a tree hashed differently could never be claimed
+    if (distributor_state.claim_mode == ClaimMode::Bitmap) != merkle_tree.bitmap_claims {
+        panic!("claim_mode mismatch");
+    }
+    if distributor_state.leaf_vesting != merkle_tree.leaf_vesting {
+        panic!("leaf_vesting mismatch");
+    }
+    if distributor_state.root == merkle_tree.merkle_root {
+        println!(
+            "root of airdrop version {} already up to date",
+            merkle_tree.airdrop_version
+        );
+        return;
+    }
+
+    let update_root_ix = Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::UpdateRoot {
+            distributor,
+            admin: keypair.pubkey(),
+            event_authority: get_event_authority_pda(&args.program_id).0,
+            program: args.program_id,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::UpdateRoot {
+            root: merkle_tree.merkle_root,
+            max_total_claim: merkle_tree.max_total_claim,
+            max_num_nodes: merkle_tree.max_num_nodes,
+        }
+        .data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_root_ix],
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "successfully updated root of airdrop version {} to revision {} with signature {signature:#?}",
+        merkle_tree.airdrop_version,
+        distributor_state.root_revision + 1,
+    );
+
+    // a corrected tree with more nodes needs bitmaps for them
+    if merkle_tree.bitmap_claims {
+        init_claim_bitmaps(args, &client, &keypair, distributor, &merkle_tree);
+    }
+
+    if merkle_tree.max_total_claim > distributor_state.max_total_claim {
+        println!(
+            "max total claim went up by {}, transfer the difference to the vault",
+            merkle_tree.max_total_claim - distributor_state.max_total_claim
+        );
+    }
+}
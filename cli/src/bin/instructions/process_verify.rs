@@ -9,6 +9,7 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
 
     // TODO fix code
     let program = args.get_program_client();
+    let token_program = get_token_program(&program.rpc(), &args.mint);
 
     for file in paths {
         let single_tree_path = file.path();
@@ -21,16 +22,19 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
             merkle_tree.airdrop_version
         );
 
-        let (distributor_pubkey, _bump) =
-            get_merkle_distributor_pda(&args.program_id, &args.mint, merkle_tree.airdrop_version);
+        let (distributor_pubkey, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
 
         if !verfify_args.skip_verify_amount {
-            let token_vault = get_associated_token_address(&distributor_pubkey, &args.mint);
+            let token_vault = get_associated_token_address_with_program_id(
+                &distributor_pubkey,
+                &args.mint,
+                &token_program,
+            );
             let token_vault_account: TokenAccount = program.account(token_vault).unwrap();
             assert_eq!(token_vault_account.amount, merkle_tree.max_total_claim);
         }
 
-        let merke_tree_state: MerkleDistributor = program.account(distributor_pubkey).unwrap();
+        let merke_tree_state = get_distributor_state(&program, distributor_pubkey).unwrap();
         assert_eq!(merke_tree_state.root, merkle_tree.merkle_root);
 
         assert_eq!(
@@ -43,8 +47,11 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
         assert_eq!(merke_tree_state.admin, verfify_args.admin);
         assert_eq!(merke_tree_state.enable_slot, verfify_args.enable_slot);
 
-        let clawback_receiver =
-            get_associated_token_address(&verfify_args.clawback_receiver_owner, &args.mint);
+        let clawback_receiver = get_associated_token_address_with_program_id(
+            &verfify_args.clawback_receiver_owner,
+            &args.mint,
+            &token_program,
+        );
         assert_eq!(merke_tree_state.clawback_receiver, clawback_receiver);
     }
 }
@@ -0,0 +1,81 @@
+use crate::*;
+
+pub fn process_withdraw_forgone(args: &Args, withdraw_forgone_args: &WithdrawForgoneArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut paths: Vec<_> = fs::read_dir(&withdraw_forgone_args.merkle_tree_path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    paths.sort_by_key(|dir| dir.path());
+
+    let mut total_withdrawn = 0;
+    for file in paths {
+        let single_tree_path = file.path();
+
+        let merkle_tree =
+            AirdropMerkleTree::new_from_file(&single_tree_path).expect("failed to read");
+
+        let (distributor, _bump) = args.get_distributor_pda(merkle_tree.airdrop_version);
+
+        let distributor_state = get_distributor_state(&program, distributor).unwrap();
+        if distributor_state.clawed_back {
+            println!("already clawback {}", merkle_tree.airdrop_version);
+            continue;
+        }
+
+        let amount = distributor_state.total_amount_forgone
+            - distributor_state.total_amount_forgone_withdrawn;
+        if amount == 0 {
+            println!(
+                "no forgone tokens to withdraw {}",
+                merkle_tree.airdrop_version
+            );
+            continue;
+        }
+
+        let withdraw_forgone_ix = Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::WithdrawForgone {
+                distributor,
+                from: distributor_state.token_vault,
+                to: withdraw_forgone_args
+                    .destination
+                    .unwrap_or(distributor_state.clawback_receiver),
+                admin: keypair.pubkey(),
+                mint: distributor_state.mint,
+                token_program: get_token_program(&client, &distributor_state.mint),
+                event_authority: get_event_authority_pda(&args.program_id).0,
+                program: args.program_id,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::WithdrawForgone { amount }.data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[withdraw_forgone_ix],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                total_withdrawn += amount;
+                println!(
+                    "Successfully withdrew {} forgone tokens from airdrop version {} ! signature: {signature:#?}",
+                    amount, merkle_tree.airdrop_version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", merkle_tree.airdrop_version, err);
+            }
+        }
+    }
+
+    println!("withdrew {} forgone tokens in total", total_withdrawn);
+}
@@ -27,6 +27,8 @@ pub fn verify_kv_proof(verify_kv_proof_args: &VerifyKvProofArgs) {
 
         assert_eq!(kv_proof.amount, local_proof.amount);
         assert_eq!(kv_proof.merkle_tree, local_proof.merkle_tree);
+        assert_eq!(kv_proof.start_ts, local_proof.start_ts);
+        assert_eq!(kv_proof.end_ts, local_proof.end_ts);
         assert_eq!(kv_proof.proof, local_proof.proof);
     }
 }
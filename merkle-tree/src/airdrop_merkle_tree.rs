@@ -27,6 +27,15 @@ pub struct UserProof {
     pub amount: u64,
     /// locked amount
     pub locked_amount: u64,
+    /// index of the leaf, needed to claim from distributors tracking claims in a bitmap
+    #[serde(default)]
+    pub leaf_index: Option<u64>,
+    /// start of the user's own vesting schedule, set for trees with per-leaf vesting
+    #[serde(default)]
+    pub start_ts: Option<i64>,
+    /// end of the user's own vesting schedule, set for trees with per-leaf vesting
+    #[serde(default)]
+    pub end_ts: Option<i64>,
     pub proof: Vec<[u8; 32]>,
 }
 
@@ -44,6 +53,12 @@ pub struct AirdropMerkleTree {
     pub airdrop_version: u64,
     pub max_num_nodes: u64,
     pub max_total_claim: u64,
+    /// Whether claims are tracked in a bitmap indexed by leaf index instead of a ClaimStatus per claimant
+    #[serde(default)]
+    pub bitmap_claims: bool,
+    /// Whether every leaf commits to its own vesting schedule instead of sharing the distributor's
+    #[serde(default)]
+    pub leaf_vesting: bool,
     pub tree_nodes: Vec<TreeNode>,
 }
 
@@ -51,25 +66,47 @@ pub type Result<T> = result::Result<T, MerkleTreeError>;
 
 impl AirdropMerkleTree {
     pub fn new(tree_nodes: Vec<TreeNode>, airdrop_version: u64) -> Result<Self> {
+        Self::new_with_bitmap_claims(tree_nodes, airdrop_version, false)
+    }
+
+    /// Build a tree whose leaves commit to their index if `bitmap_claims` is set,
+    /// see [TreeNode::bitmap_hash]
+    pub fn new_with_bitmap_claims(
+        tree_nodes: Vec<TreeNode>,
+        airdrop_version: u64,
+        bitmap_claims: bool,
+    ) -> Result<Self> {
         // Combine tree nodes with the same claimant, while retaining original order
         let mut tree_nodes_map: IndexMap<Pubkey, TreeNode> = IndexMap::new();
         for tree_node in tree_nodes {
             let claimant = tree_node.claimant;
-            tree_nodes_map
-                .entry(claimant)
-                .and_modify(|n| {
+            match tree_nodes_map.get_mut(&claimant) {
+                Some(n) => {
+                    // entries on different schedules can't share a leaf
+                    if n.start_ts != tree_node.start_ts || n.end_ts != tree_node.end_ts {
+                        return Err(MerkleValidationError(format!(
+                            "duplicate claimant {} found with different vesting schedules",
+                            n.claimant
+                        )));
+                    }
                     println!("duplicate claimant {} found, combining", n.claimant);
                     n.amount = n.amount.checked_add(tree_node.amount).unwrap();
-                })
-                .or_insert_with(|| tree_node); // If not exists, insert a new entry
+                }
+                None => {
+                    tree_nodes_map.insert(claimant, tree_node);
+                }
+            }
         }
 
         // Convert IndexMap back to Vec while preserving the order
         let mut tree_nodes: Vec<TreeNode> = tree_nodes_map.values().cloned().collect();
+        for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
+            tree_node.leaf_index = Some(i as u64);
+        }
 
         let hashed_nodes = tree_nodes
             .iter()
-            .map(|claim_info| claim_info.hash().to_bytes())
+            .map(|claim_info| hash_node(claim_info, bitmap_claims))
             .collect::<Vec<_>>();
 
         let tree = MerkleTree::new(&hashed_nodes[..], true);
@@ -79,6 +116,9 @@ impl AirdropMerkleTree {
         }
 
         let max_total_claim = get_max_total_claim(tree_nodes.as_ref());
+        let leaf_vesting = tree_nodes
+            .iter()
+            .any(|n| n.start_ts.is_some() || n.end_ts.is_some());
         let tree = AirdropMerkleTree {
             merkle_root: tree
                 .get_root()
@@ -87,6 +127,8 @@ impl AirdropMerkleTree {
             airdrop_version,
             max_num_nodes: tree_nodes.len() as u64,
             max_total_claim,
+            bitmap_claims,
+            leaf_vesting,
             tree_nodes,
         };
 
@@ -99,26 +141,27 @@ impl AirdropMerkleTree {
     }
 
     /// Load a merkle tree from a csv path
-    pub fn new_from_csv(path: &PathBuf, version: u64, decimals: u32) -> Result<Self> {
+    pub fn new_from_csv(
+        path: &PathBuf,
+        version: u64,
+        decimals: u32,
+        bitmap_claims: bool,
+    ) -> Result<Self> {
         let csv_entries = CsvEntry::new_from_file(path)?;
-        let tree_nodes: Vec<TreeNode> = csv_entries
-            .into_iter()
-            .map(|x| TreeNode::from_csv(x, decimals))
-            .collect();
-        let tree = Self::new(tree_nodes, version)?;
-        Ok(tree)
+        Self::new_from_entries(csv_entries, version, decimals, bitmap_claims)
     }
 
     pub fn new_from_entries(
         csv_entries: Vec<CsvEntry>,
         version: u64,
         decimals: u32,
+        bitmap_claims: bool,
     ) -> Result<Self> {
         let tree_nodes: Vec<TreeNode> = csv_entries
             .into_iter()
             .map(|x| TreeNode::from_csv(x, decimals))
             .collect();
-        let tree = Self::new(tree_nodes, version)?;
+        let tree = Self::new_with_bitmap_claims(tree_nodes, version, bitmap_claims)?;
         Ok(tree)
     }
 
@@ -185,6 +228,34 @@ impl AirdropMerkleTree {
             )));
         }
 
+        // bitmap claims are made once, leaving nothing to track locked amounts with
+        if self.bitmap_claims && self.tree_nodes.iter().any(|n| n.locked_amount() > 0) {
+            return Err(MerkleValidationError(
+                "Locked amounts are not supported with bitmap claims".to_string(),
+            ));
+        }
+
+        if self.leaf_vesting {
+            if self.bitmap_claims {
+                return Err(MerkleValidationError(
+                    "Per-leaf vesting is not supported with bitmap claims".to_string(),
+                ));
+            }
+
+            // the distributor expects every leaf to commit to a schedule, or none of them
+            for node in self.tree_nodes.iter() {
+                match node.vesting() {
+                    Some((start_ts, end_ts)) if start_ts < end_ts => {}
+                    _ => {
+                        return Err(MerkleValidationError(format!(
+                            "Claimant {} needs a start_ts before its end_ts, as other leaves have a vesting schedule",
+                            node.claimant
+                        )));
+                    }
+                }
+            }
+        }
+
         if self.verify_proof().is_err() {
             return Err(MerkleValidationError(
                 "Merkle root is invalid given nodes".to_string(),
@@ -202,7 +273,7 @@ impl AirdropMerkleTree {
         let hashed_nodes: Vec<[u8; 32]> = self
             .tree_nodes
             .iter()
-            .map(|n| n.hash().to_bytes())
+            .map(|n| hash_node(n, self.bitmap_claims))
             .collect();
         let mk = MerkleTree::new(&hashed_nodes[..], true);
 
@@ -235,6 +306,14 @@ impl AirdropMerkleTree {
     }
 }
 
+fn hash_node(node: &TreeNode, bitmap_claims: bool) -> [u8; 32] {
+    if bitmap_claims {
+        node.bitmap_hash().to_bytes()
+    } else {
+        node.hash().to_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -270,6 +349,9 @@ mod tests {
                 claimant: new_test_key(),
                 amount: rand_balance(),
                 locked_amount: Some(rand_balance()),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
                 // total_unlocked_staker: rand_balance(),
                 // total_locked_staker: rand_balance(),
@@ -291,6 +373,9 @@ mod tests {
             claimant: Pubkey::default(),
             amount: 2,
             locked_amount: Some(0),
+            leaf_index: None,
+            start_ts: None,
+            end_ts: None,
             proof: None,
         }];
         let merkle_tree = AirdropMerkleTree::new(tree_nodes, 0).unwrap();
@@ -305,18 +390,27 @@ mod tests {
                 claimant: pubkey!("FLYqJsmJ5AGMxMxK3Qy1rSen4ES2dqqo6h51W3C1tYS"),
                 amount: (100 * u64::pow(10, 9)),
                 locked_amount: Some(0),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqED"),
                 amount: (100 * u64::pow(10, 9)),
                 locked_amount: Some(0),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqEH"),
                 amount: (100 * u64::pow(10, 9)),
                 locked_amount: Some(1),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
             },
         ];
@@ -347,18 +441,27 @@ mod tests {
                 claimant: duplicate_pubkey,
                 amount: 10,
                 locked_amount: Some(10),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
             },
             TreeNode {
                 claimant: duplicate_pubkey,
                 amount: 1,
                 locked_amount: Some(10),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
             },
             TreeNode {
                 claimant: Pubkey::new_unique(),
                 amount: 0,
                 locked_amount: Some(10),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
                 proof: None,
             },
         ];
@@ -368,4 +471,129 @@ mod tests {
         assert_eq!(tree.tree_nodes[0].amount, 11);
         assert_eq!(tree.tree_nodes[0].locked_amount, Some(10));
     }
+
+    #[test]
+    fn test_new_merkle_tree_sets_leaf_indices() {
+        let tree_nodes = (0..3)
+            .map(|_| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 10,
+                locked_amount: None,
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
+                proof: None,
+            })
+            .collect();
+
+        let tree = AirdropMerkleTree::new(tree_nodes, 0).unwrap();
+        for (i, node) in tree.tree_nodes.iter().enumerate() {
+            assert_eq!(node.leaf_index, Some(i as u64));
+        }
+    }
+
+    #[test]
+    fn test_new_merkle_tree_bitmap_claims() {
+        let tree_nodes: Vec<TreeNode> = (0..5)
+            .map(|i| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 10 + i,
+                locked_amount: Some(0),
+                leaf_index: None,
+                start_ts: None,
+                end_ts: None,
+                proof: None,
+            })
+            .collect();
+
+        let bitmap_tree =
+            AirdropMerkleTree::new_with_bitmap_claims(tree_nodes.clone(), 0, true).unwrap();
+        let tree = AirdropMerkleTree::new(tree_nodes, 0).unwrap();
+
+        assert!(bitmap_tree.bitmap_claims);
+        assert!(bitmap_tree.verify_proof().is_ok());
+        // the leaf index is part of the leaf, so the roots differ
+        assert_ne!(bitmap_tree.merkle_root, tree.merkle_root);
+    }
+
+    #[test]
+    fn test_new_merkle_tree_bitmap_claims_rejects_locked_amount() {
+        let tree_nodes = vec![TreeNode {
+            claimant: Pubkey::new_unique(),
+            amount: 10,
+            locked_amount: Some(1),
+            leaf_index: None,
+            start_ts: None,
+            end_ts: None,
+            proof: None,
+        }];
+
+        assert!(AirdropMerkleTree::new_with_bitmap_claims(tree_nodes, 0, true).is_err());
+    }
+
+    #[test]
+    fn test_new_merkle_tree_leaf_vesting() {
+        let tree_nodes: Vec<TreeNode> = (0..3)
+            .map(|i| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 10,
+                locked_amount: Some(100),
+                leaf_index: None,
+                start_ts: Some(1_000),
+                end_ts: Some(2_000 + i),
+                proof: None,
+            })
+            .collect();
+
+        let tree = AirdropMerkleTree::new(tree_nodes.clone(), 0).unwrap();
+        assert!(tree.leaf_vesting);
+        assert!(tree.verify_proof().is_ok());
+
+        // the schedule is part of the leaf, so the root differs from a shared schedule tree
+        let shared_schedule_nodes = tree_nodes
+            .into_iter()
+            .map(|n| TreeNode {
+                start_ts: None,
+                end_ts: None,
+                ..n
+            })
+            .collect();
+        let shared_schedule_tree = AirdropMerkleTree::new(shared_schedule_nodes, 0).unwrap();
+        assert!(!shared_schedule_tree.leaf_vesting);
+        assert_ne!(tree.merkle_root, shared_schedule_tree.merkle_root);
+    }
+
+    #[test]
+    fn test_new_merkle_tree_leaf_vesting_rejects_partial_schedules() {
+        let mut tree_nodes: Vec<TreeNode> = (0..2)
+            .map(|_| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 10,
+                locked_amount: Some(100),
+                leaf_index: None,
+                start_ts: Some(1_000),
+                end_ts: Some(2_000),
+                proof: None,
+            })
+            .collect();
+
+        // one leaf without a schedule
+        let mut missing = tree_nodes.clone();
+        missing[1].start_ts = None;
+        missing[1].end_ts = None;
+        assert!(AirdropMerkleTree::new(missing, 0).is_err());
+
+        // a schedule ending before it starts
+        let mut inverted = tree_nodes.clone();
+        inverted[1].end_ts = Some(500);
+        assert!(AirdropMerkleTree::new(inverted, 0).is_err());
+
+        // bitmap claims can't honour a schedule
+        assert!(AirdropMerkleTree::new_with_bitmap_claims(tree_nodes.clone(), 0, true).is_err());
+
+        // the same claimant on two schedules
+        tree_nodes[1].claimant = tree_nodes[0].claimant;
+        tree_nodes[1].end_ts = Some(3_000);
+        assert!(AirdropMerkleTree::new(tree_nodes, 0).is_err());
+    }
 }
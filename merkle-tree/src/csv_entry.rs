@@ -15,6 +15,10 @@ pub struct CsvEntry {
     pub amount: u64,
     /// amount locked, (ui amount)
     pub locked_amount: Option<u64>,
+    /// start of the claimant's own vesting schedule (Unix Timestamp), optional column
+    pub start_ts: Option<i64>,
+    /// end of the claimant's own vesting schedule (Unix Timestamp), optional column
+    pub end_ts: Option<i64>,
 }
 
 impl CsvEntry {
@@ -48,5 +52,21 @@ mod tests {
             "4SX6nqv5VRLMoNfYM5phvHgcBNcBEwUEES4qPPjf1EqS"
         );
         assert_eq!(entries[0].amount, 1000);
+        assert_eq!(entries[0].start_ts, None);
+        assert_eq!(entries[0].end_ts, None);
+    }
+
+    #[test]
+    fn test_csv_parsing_with_vesting() {
+        let path = PathBuf::from("./test_fixtures/test_csv_vesting.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].locked_amount, Some(4000));
+        assert_eq!(entries[0].start_ts, Some(1_700_000_000));
+        assert_eq!(entries[0].end_ts, Some(1_731_536_000));
+        assert_eq!(entries[2].start_ts, Some(1_700_000_000));
+        assert_eq!(entries[2].end_ts, Some(1_700_086_400));
     }
 }
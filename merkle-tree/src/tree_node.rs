@@ -15,16 +15,54 @@ pub struct TreeNode {
     pub amount: u64,
     /// Locked amount
     pub locked_amount: Option<u64>,
+    /// Index of the leaf in the tree, set when the tree is built
+    #[serde(default)]
+    pub leaf_index: Option<u64>,
+    /// Start of this claimant's own vesting schedule (Unix Timestamp), set together with end_ts
+    #[serde(default)]
+    pub start_ts: Option<i64>,
+    /// End of this claimant's own vesting schedule (Unix Timestamp), set together with start_ts
+    #[serde(default)]
+    pub end_ts: Option<i64>,
     /// Claimant's proof of inclusion in the Merkle Tree
     pub proof: Option<Vec<[u8; 32]>>,
 }
 
 impl TreeNode {
+    /// Leaf hash for trees whose claims are tracked in a ClaimStatus per claimant.
+    /// Commits to the claimant's vesting schedule if it has one, see [TreeNode::vesting]
     pub fn hash(&self) -> Hash {
+        match self.vesting() {
+            Some((start_ts, end_ts)) => hashv(&[
+                &self.claimant.to_bytes(),
+                &self.amount.to_le_bytes(),
+                &self.locked_amount.unwrap_or(0).to_le_bytes(),
+                &start_ts.to_le_bytes(),
+                &end_ts.to_le_bytes(),
+            ]),
+            None => hashv(&[
+                &self.claimant.to_bytes(),
+                &self.amount.to_le_bytes(),
+                &self.locked_amount.unwrap_or(0).to_le_bytes(),
+            ]),
+        }
+    }
+
+    /// Return the claimant's own (start_ts, end_ts) vesting schedule, if both are set
+    pub fn vesting(&self) -> Option<(i64, i64)> {
+        match (self.start_ts, self.end_ts) {
+            (Some(start_ts), Some(end_ts)) => Some((start_ts, end_ts)),
+            _ => None,
+        }
+    }
+
+    /// Leaf hash for trees whose claims are tracked in a bitmap.
+    /// Commits to the leaf index so each claimant maps to exactly one bit, locked amounts are not supported.
+    pub fn bitmap_hash(&self) -> Hash {
         hashv(&[
+            &self.leaf_index.expect("leaf index not set").to_le_bytes(),
             &self.claimant.to_bytes(),
             &self.amount.to_le_bytes(),
-            &self.locked_amount.unwrap_or(0).to_le_bytes(),
         ])
     }
 
@@ -59,6 +97,9 @@ impl TreeNode {
             locked_amount: entry
                 .locked_amount
                 .map(|amount| ui_amount_to_token_amount(amount, decimals)),
+            leaf_index: None,
+            start_ts: entry.start_ts,
+            end_ts: entry.end_ts,
             proof: None,
         };
         node
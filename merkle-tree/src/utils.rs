@@ -25,7 +25,27 @@ pub fn get_max_total_claim(nodes: &[TreeNode]) -> u64 {
         .unwrap()
 }
 
+/// Address of the distributor for `version` of `mint` created by the `base` admin, who is part of the
+/// seeds so nobody else can occupy it.
 pub fn get_merkle_distributor_pda(
+    program_id: &Pubkey,
+    base: &Pubkey,
+    mint: &Pubkey,
+    version: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"MerkleDistributor".as_ref(),
+            base.as_ref(),
+            mint.as_ref(),
+            version.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Address of a distributor created before its admin was part of the seeds, for existing campaigns.
+pub fn get_legacy_merkle_distributor_pda(
     program_id: &Pubkey,
     mint: &Pubkey,
     version: u64,
@@ -55,6 +75,43 @@ pub fn get_claim_status_pda(
     )
 }
 
+/// `bitmap_index` is the leaf index divided by the number of leaves each bitmap covers
+pub fn get_claim_bitmap_pda(
+    program_id: &Pubkey,
+    distributor: &Pubkey,
+    bitmap_index: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimBitmap".as_ref(),
+            distributor.to_bytes().as_ref(),
+            bitmap_index.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Signer of the self-CPIs the program emits its events through
+pub fn get_event_authority_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority".as_ref()], program_id)
+}
+
+/// Singleton listing the keys allowed to create distributors
+pub fn get_program_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ProgramConfig".as_ref()], program_id)
+}
+
+/// Optional metadata describing the distributor
+pub fn get_distributor_metadata_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"DistributorMetadata".as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
 #[derive(Debug)]
 pub struct MerkleValidationError {
     pub msg: String,
@@ -69,6 +126,9 @@ mod tests {
             claimant,
             amount,
             locked_amount: Some(locked_amount),
+            leaf_index: None,
+            start_ts: None,
+            end_ts: None,
             proof: None,
         }
     }
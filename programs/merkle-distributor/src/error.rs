@@ -45,4 +45,58 @@ pub enum ErrorCode {
     CannotCloseDistributor,
     #[msg("Cannot close claim status")]
     CannotCloseClaimStatus,
+    #[msg("Start claim pct must be greater than 0 and at most 100%")]
+    InvalidStartClaimPct,
+    #[msg("Instruction does not support the distributor claim mode")]
+    InvalidClaimMode,
+    #[msg("Leaf index is out of range")]
+    InvalidLeafIndex,
+    #[msg("Leaf already claimed")]
+    AlreadyClaimed,
+    #[msg("Cliff must be between the start and end timestamps")]
+    InvalidCliff,
+    #[msg("Unlock period must be between 0 and the vesting duration")]
+    InvalidUnlockPeriod,
+    #[msg("Instruction does not match whether the distributor uses per-leaf vesting")]
+    LeafVestingMismatch,
+    #[msg("Amount exceeds the forgone tokens left to withdraw")]
+    InsufficientForgoneTokens,
+    #[msg("Vault holds less than the locked amounts owed to claimants")]
+    InsufficientVaultForLocked,
+    #[msg("Root can only be updated before the enable slot and the first claim")]
+    CannotUpdateRoot,
+    #[msg("Distributor is paused")]
+    DistributorPaused,
+    #[msg("Distributor is not paused")]
+    DistributorNotPaused,
+    #[msg("Locked tokens remain to be withdrawn")]
+    ClaimStatusNotSettled,
+    #[msg("Distributor has not been clawed back")]
+    NotClawedBack,
+    #[msg("Locked tokens are still owed to claimants")]
+    LockedAmountsOutstanding,
+    #[msg("Funder token account holds less than the max total claim")]
+    InsufficientFunderBalance,
+    #[msg("Claim end must be in the future and no later than clawback start")]
+    InvalidClaimEndTs,
+    #[msg("New claims are no longer accepted")]
+    ClaimWindowClosed,
+    #[msg("Distributor already uses the current account layout")]
+    AlreadyMigrated,
+    #[msg("Batch claims need a distributor, claim status and vault of the mint for each claim")]
+    InvalidBatchAccounts,
+    #[msg("Deposit program is not an executable program allowlisted by the admin")]
+    InvalidDepositProgram,
+    #[msg("Too many distributor creators")]
+    TooManyCreators,
+    #[msg("Distributor metadata field is too long")]
+    MetadataTooLong,
+    #[msg("Claim fee is either lamports or at most MAX_CLAIM_FEE_BPS basis points, for ClaimStatus distributors")]
+    InvalidClaimFee,
+    #[msg("Claim fee receiver is missing or does not match the distributor")]
+    InvalidClaimFeeReceiver,
+    #[msg("Mints with a transfer hook are not supported")]
+    TransferHookNotSupported,
+    #[msg("Claim status rent was paid by a sponsor and cannot be reclaimed by the claimant")]
+    RentSponsored,
 }
@@ -0,0 +1,46 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::AdminAcceptedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::accept_admin] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin proposed with propose_admin
+    #[account(address = distributor.pending_admin @ ErrorCode::Unauthorized)]
+    pub pending_admin: Signer<'info>,
+}
+
+/// Hands the distributor over to the pending admin and clears the proposal.
+/// CHECK:
+///     1. The pending admin signed, without a proposal it is the default pubkey nobody can sign for
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let old_admin = distributor.admin;
+    distributor.admin = ctx.accounts.pending_admin.key();
+    distributor.pending_admin = Pubkey::default();
+
+    // Note: might get truncated, do not rely on
+    msg!("set new admin to {}", distributor.admin);
+    emit_cpi!(AdminAcceptedEvent {
+        distributor: distributor.key(),
+        old_admin,
+        new_admin: distributor.admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,274 @@
+use anchor_lang::{
+    context::Context, prelude::*, system_program::System, Accounts, Discriminator, Key, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    instructions::{execute_claim_locked, execute_new_claim, new_claim::ClaimFeeAccounts},
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+    utils::create_program_account,
+};
+
+/// Leaf of the claimant in one of the distributors of a [merkle_distributor::batch_claim].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchClaimArgs {
+    /// Unlocked amount of the leaf, ignored if the claim status already exists
+    pub amount_unlocked: u64,
+    /// Locked amount of the leaf, ignored if the claim status already exists
+    pub amount_locked: u64,
+    /// Proof of the leaf, empty if the claim status already exists
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// [merkle_distributor::batch_claim] accounts.
+/// Each claim takes a (distributor, claim status, vault) triple of writable remaining accounts, in the order of the claims.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchClaim<'info> {
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = claimant.key()
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// Pays the rent for new claim status PDAs, can be the claimant or a relayer sponsoring the claims.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint distributed by every distributor of the batch.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// Receives the claim fee of new claims, required if a distributor of the batch charges one.
+    /// CHECK: checked against each distributor's claim_fee_receiver when charging the fee
+    #[account(mut)]
+    pub claim_fee_receiver: Option<UncheckedAccount<'info>>,
+}
+
+/// Claims from several distributors of the same mint at once, in the order of `claims`.
+/// For each distributor, creates the claim like new_claim if its claim status doesn't exist yet,
+/// or withdraws unlocked tokens like claim_locked otherwise, and emits the same event.
+/// Claims with nothing to do are skipped instead of failing the batch, see [is_batch_claim_skipped].
+/// Distributors with per-leaf vesting are not supported, and the distributors
+/// charging a claim fee on new claims must share the claim fee receiver.
+/// CHECK:
+///     1. There is a remaining accounts triple per claim
+///     2. Each distributor is of the mint and each vault is its distributor's vault
+///     3. Each claim status is the claimant's PDA for its distributor
+///     4. Everything new_claim or claim_locked checks
+#[allow(clippy::result_large_err)]
+pub fn handle_batch_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchClaim<'info>>,
+    claims: Vec<BatchClaimArgs>,
+) -> Result<()> {
+    require!(
+        !claims.is_empty() && ctx.remaining_accounts.len() == claims.len() * 3,
+        ErrorCode::InvalidBatchAccounts
+    );
+
+    let claimant = ctx.accounts.claimant.key();
+    let curr_ts = Clock::get()?.unix_timestamp;
+    for (accounts, claim) in ctx.remaining_accounts.chunks(3).zip(claims) {
+        let (distributor_info, claim_status_info, vault_info) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        let mut distributor = Account::<MerkleDistributor>::try_from(distributor_info)?;
+        let vault = InterfaceAccount::<TokenAccount>::try_from(vault_info)?;
+        require!(
+            distributor.mint == ctx.accounts.mint.key() && vault.key() == distributor.token_vault,
+            ErrorCode::InvalidBatchAccounts
+        );
+
+        let (claim_status_key, bump) = Pubkey::find_program_address(
+            &[
+                b"ClaimStatus".as_ref(),
+                claimant.as_ref(),
+                distributor.key().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            claim_status_info.key(),
+            claim_status_key,
+            ErrorCode::InvalidBatchAccounts
+        );
+
+        let skipped = if claim_status_info.owner == &System::id() {
+            is_batch_claim_skipped(&distributor, None, curr_ts)?
+        } else {
+            is_batch_claim_skipped(
+                &distributor,
+                Some(&claim_status_info.try_borrow_data()?[..]),
+                curr_ts,
+            )?
+        };
+        if skipped {
+            continue;
+        }
+
+        if claim_status_info.owner == &System::id() {
+            create_program_account(
+                &ctx.accounts.payer,
+                claim_status_info,
+                &ctx.accounts.system_program,
+                ClaimStatus::LEN,
+                &[&[
+                    b"ClaimStatus".as_ref(),
+                    claimant.as_ref(),
+                    distributor.key().as_ref(),
+                    &[bump],
+                ]],
+            )?;
+            claim_status_info.try_borrow_mut_data()?[..8]
+                .copy_from_slice(&ClaimStatus::DISCRIMINATOR);
+
+            let mut claim_status = Account::<ClaimStatus>::try_from(claim_status_info)?;
+            let event = execute_new_claim(
+                &mut distributor,
+                &mut claim_status,
+                &vault,
+                &ctx.accounts.to,
+                &ctx.accounts.claimant,
+                &ctx.accounts.payer,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                claim.amount_unlocked,
+                claim.amount_locked,
+                None,
+                ClaimFeeAccounts::new(
+                    &ctx.accounts.claim_fee_receiver,
+                    &ctx.accounts.payer,
+                    &ctx.accounts.system_program,
+                ),
+                claim.proof,
+            )?;
+            claim_status.exit(&crate::ID)?;
+            emit_cpi!(event);
+        } else {
+            let mut claim_status = Account::<ClaimStatus>::try_from(claim_status_info)?;
+            let event = execute_claim_locked(
+                &mut distributor,
+                &mut claim_status,
+                &vault,
+                &ctx.accounts.to,
+                &ctx.accounts.claimant,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                None,
+            )?;
+            claim_status.exit(&crate::ID)?;
+            emit_cpi!(event);
+        }
+
+        // written back before the next claim, which may be from the same distributor
+        distributor.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Whether a claim of a batch has nothing to do, so that claiming all of a user's airdrops keeps working once some
+/// are finished or still locked: the distributor is paused, the claim status is a tombstone left by
+/// close_settled_claim_status, or nothing is withdrawable from it yet.
+/// `claim_status_data` is None if the claim status doesn't exist yet.
+#[allow(clippy::result_large_err)]
+pub(crate) fn is_batch_claim_skipped(
+    distributor: &MerkleDistributor,
+    claim_status_data: Option<&[u8]>,
+    curr_ts: i64,
+) -> Result<bool> {
+    if distributor.paused {
+        return Ok(true);
+    }
+    let Some(mut data) = claim_status_data else {
+        return Ok(false);
+    };
+    let Ok(claim_status) = ClaimStatus::try_deserialize(&mut data) else {
+        return Ok(true);
+    };
+    let amount = claim_status.amount_withdrawable(
+        curr_ts,
+        distributor.start_ts,
+        distributor.end_ts,
+        distributor.cliff_ts,
+        distributor.unlock_period,
+    )?;
+    Ok(amount == 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            start_ts: 100,
+            end_ts: 200,
+            ..MerkleDistributor::default()
+        }
+    }
+
+    fn claim_status_data(locked_amount_withdrawn: u64) -> Vec<u8> {
+        let claim_status = ClaimStatus {
+            locked_amount: 1_000,
+            locked_amount_withdrawn,
+            ..ClaimStatus::default()
+        };
+        let mut data = vec![];
+        claim_status.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn is_batch_claim_skipped_new_claim() {
+        assert!(!is_batch_claim_skipped(&distributor(), None, 150).unwrap());
+    }
+
+    #[test]
+    fn is_batch_claim_skipped_paused() {
+        let distributor = MerkleDistributor {
+            paused: true,
+            ..distributor()
+        };
+        assert!(is_batch_claim_skipped(&distributor, None, 150).unwrap());
+        assert!(
+            is_batch_claim_skipped(&distributor, Some(&claim_status_data(0)[..]), 150).unwrap()
+        );
+    }
+
+    #[test]
+    fn is_batch_claim_skipped_tombstone() {
+        assert!(
+            is_batch_claim_skipped(&distributor(), Some(&ClaimStatus::DISCRIMINATOR[..]), 150)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn is_batch_claim_skipped_nothing_withdrawable() {
+        // nothing unlocked before start_ts
+        assert!(
+            is_batch_claim_skipped(&distributor(), Some(&claim_status_data(0)[..]), 50).unwrap()
+        );
+        // everything unlocked so far already withdrawn
+        assert!(
+            is_batch_claim_skipped(&distributor(), Some(&claim_status_data(500)[..]), 150).unwrap()
+        );
+        assert!(
+            !is_batch_claim_skipped(&distributor(), Some(&claim_status_data(0)[..]), 150).unwrap()
+        );
+    }
+}
@@ -1,21 +1,23 @@
 use anchor_lang::{
-    accounts::{account::Account, program::Program, signer::Signer},
-    context::{Context, CpiContext},
-    emit,
+    accounts::{account::Account, signer::Signer},
+    context::Context,
     prelude::*,
-    Accounts, Result, ToAccountInfo,
+    Accounts, Result,
 };
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::ClaimedEvent,
-        merkle_distributor::MerkleDistributor,
+        claim_status::ClaimStatus,
+        claimed_event::ClaimedEvent,
+        merkle_distributor::{ClawbackMode, MerkleDistributor},
     },
+    utils::transfer_from_vault,
 };
 
 /// [merkle_distributor::claim_locked] accounts.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimLocked<'info> {
     /// The [MerkleDistributor].
@@ -37,70 +39,104 @@ pub struct ClaimLocked<'info> {
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault,
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
     /// Claimant must sign the transaction and can only claim on behalf of themself
     #[account(mut, token::authority = claimant.key())]
-    pub to: Account<'info, TokenAccount>,
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Who is claiming the tokens.
     #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
     pub claimant: Signer<'info>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Claim locked tokens as they become unlocked.
 /// Check:
-///     1. The claim window has not expired and the distributor has not been clawed back
+///     1. The claim window has not expired, the distributor is not paused and has not been clawed
+///        back unless its clawback preserved locked amounts, and uses the distributor's vesting schedule
 ///     2. The withdraw-able amount is greater than 0
 ///     3. The locked amount withdrawn is ≤ than the locked amount
 ///     4. The distributor amount claimed is ≤ than the max total claim
 #[allow(clippy::result_large_err)]
-pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
-    let distributor = &ctx.accounts.distributor;
+pub fn handle_claim_locked<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>,
+) -> Result<()> {
+    let event = execute_claim_locked(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        None,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
 
-    let claim_status = &mut ctx.accounts.claim_status;
+/// Shared by [handle_claim_locked], [crate::instructions::handle_claim_locked_to] and
+/// [crate::instructions::handle_claim_locked_with_vesting].
+/// `vesting` is the claimant's own (start_ts, end_ts), required if the distributor uses per-leaf vesting,
+/// and must already be verified against the merkle root.
+/// Returns the [ClaimedEvent] for the handler to emit, as emit_cpi! needs its Context.
+#[allow(clippy::result_large_err)]
+pub(crate) fn execute_claim_locked<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claim_status: &mut Account<'info, ClaimStatus>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    claimant: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    vesting: Option<(i64, i64)>,
+) -> Result<ClaimedEvent> {
     let curr_ts = Clock::get()?.unix_timestamp;
     let curr_slot = Clock::get()?.slot;
 
-    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(
+        vesting.is_some() == distributor.leaf_vesting,
+        ErrorCode::LeafVestingMismatch
+    );
+    let (start_ts, end_ts) = vesting.unwrap_or((distributor.start_ts, distributor.end_ts));
+
+    // a clawback preserving locked amounts left them in the vault for their claimants
+    require!(
+        !distributor.clawed_back || distributor.clawback_mode == ClawbackMode::PreserveLocked,
+        ErrorCode::ClaimExpired
+    );
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
 
     require!(
         distributor.enable_slot <= curr_slot,
         ErrorCode::ClaimingIsNotStarted
     );
 
-    let amount =
-        claim_status.amount_withdrawable(curr_ts, distributor.start_ts, distributor.end_ts)?;
+    let amount = claim_status.amount_withdrawable(
+        curr_ts,
+        start_ts,
+        end_ts,
+        distributor.cliff_ts,
+        distributor.unlock_period,
+    )?;
 
     require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
 
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        amount,
-    )?;
+    let transfer_fee = transfer_from_vault(distributor, from, to, mint, token_program, amount)?;
 
     claim_status.locked_amount_withdrawn = claim_status
         .locked_amount_withdrawn
@@ -112,19 +148,23 @@ pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
         ErrorCode::ExceededMaxClaim
     );
 
-    let distributor = &mut ctx.accounts.distributor;
     distributor.total_amount_claimed = distributor
         .total_amount_claimed
         .checked_add(amount)
         .ok_or(ErrorCode::ArithmeticError)?;
 
+    // saturating for claims created before total_locked_outstanding was tracked
+    distributor.total_locked_outstanding =
+        distributor.total_locked_outstanding.saturating_sub(amount);
+
     require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
+        distributor.total_amount_claimed + distributor.total_claim_fee_amount
+            <= distributor.max_total_claim,
         ErrorCode::ExceededMaxClaim
     );
 
-    let remaining_seconds = match curr_ts < distributor.end_ts {
-        true => distributor.end_ts - curr_ts,
+    let remaining_seconds = match curr_ts < end_ts {
+        true => end_ts - curr_ts,
         false => 0,
     };
 
@@ -138,9 +178,13 @@ pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
         days,
         seconds_after_days,
     );
-    emit!(ClaimedEvent {
-        claimant: ctx.accounts.claimant.key(),
+
+    Ok(ClaimedEvent {
+        distributor: distributor.key(),
+        version: distributor.version,
+        claimant: claimant.key(),
+        destination: to.key(),
         amount,
-    });
-    Ok(())
+        transfer_fee,
+    })
 }
@@ -0,0 +1,80 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    instructions::claim_locked::execute_claim_locked,
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::claim_locked_to] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimLockedTo<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    /// Any token account of the distributor mint, chosen by the claimant
+    #[account(mut, token::mint = distributor.mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    pub claimant: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim locked tokens as they become unlocked, sending them to a token account picked by the
+/// claimant instead of one they own.
+/// Same as [crate::instructions::handle_claim_locked] otherwise.
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_to<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLockedTo<'info>>,
+) -> Result<()> {
+    let event = execute_claim_locked(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        None,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
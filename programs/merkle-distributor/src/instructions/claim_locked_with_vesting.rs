@@ -0,0 +1,49 @@
+use anchor_lang::{context::Context, prelude::*, Result};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::{execute_claim_locked, new_claim::hash_leaf, ClaimLocked},
+};
+
+/// Claim locked tokens as they unlock on the claimant's own vesting schedule.
+/// The schedule isn't stored in the [crate::state::claim_status::ClaimStatus], so it is proven
+/// again against the merkle root together with the amounts recorded at the first claim.
+/// Same as [crate::instructions::handle_claim_locked] otherwise.
+/// Check:
+///     1. The distributor uses per-leaf vesting
+///     2. The merkle proof is valid for the claim status amounts and vesting schedule
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_with_vesting<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>,
+    start_ts: i64,
+    end_ts: i64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let claim_status = &ctx.accounts.claim_status;
+    let node = hash_leaf(
+        &claim_status.claimant,
+        claim_status.unlocked_amount,
+        claim_status.locked_amount,
+        Some((start_ts, end_ts)),
+    );
+
+    require!(
+        verify(proof, ctx.accounts.distributor.root, node),
+        ErrorCode::InvalidProof
+    );
+
+    let event = execute_claim_locked(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        Some((start_ts, end_ts)),
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
@@ -1,14 +1,19 @@
 // Instruction to clawback funds once they have expired
 
 use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::ClawbackEvent,
+        merkle_distributor::{ClawbackMode, MerkleDistributor},
+    },
+    utils::transfer_from_vault,
 };
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
-
 /// [merkle_distributor::clawback] accounts.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Clawback<'info> {
     /// The [MerkleDistributor].
@@ -18,15 +23,16 @@ pub struct Clawback<'info> {
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// The Clawback token account.
     #[account(mut, address = distributor.clawback_receiver)]
-    pub to: Account<'info, TokenAccount>,
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Claimant account
     /// Anyone can claw back the funds
@@ -35,21 +41,28 @@ pub struct Clawback<'info> {
     /// The [System] program.
     pub system_program: Program<'info, System>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Claws back unclaimed tokens by:
 /// 1. Checking that the lockup has expired
-/// 2. Transferring remaining funds from the vault to the clawback receiver
+/// 2. Transferring remaining funds from the vault to the clawback receiver, minus the locked
+///    amounts still owed to claimants if the distributor preserves them
 /// 3. Marking the distributor as clawed back
 /// CHECK:
 ///     1. The distributor has not already been clawed back
+///     2. The distributor is not paused
 #[allow(clippy::result_large_err)]
-pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
+pub fn handle_clawback<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
     let distributor = &ctx.accounts.distributor;
 
     require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
 
     let curr_ts = Clock::get()?.unix_timestamp;
 
@@ -57,29 +70,42 @@ pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
         return Err(ErrorCode::ClawbackBeforeStart.into());
     }
 
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        ctx.accounts.from.amount,
+    let amount = match distributor.clawback_mode {
+        ClawbackMode::Full => ctx.accounts.from.amount,
+        ClawbackMode::PreserveLocked => ctx
+            .accounts
+            .from
+            .amount
+            .checked_sub(distributor.total_locked_outstanding)
+            .ok_or(ErrorCode::InsufficientVaultForLocked)?,
+    };
+
+    transfer_from_vault(
+        &ctx.accounts.distributor,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
     )?;
 
     let distributor = &mut ctx.accounts.distributor;
 
     distributor.clawed_back = true;
 
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Clawed back {} tokens, left {} locked tokens owed to claimants",
+        amount,
+        ctx.accounts.from.amount - amount,
+    );
+    emit_cpi!(ClawbackEvent {
+        distributor: distributor.key(),
+        clawback_receiver: ctx.accounts.to.key(),
+        amount,
+        amount_preserved: ctx.accounts.from.amount - amount,
+        timestamp: curr_ts,
+    });
+
     Ok(())
 }
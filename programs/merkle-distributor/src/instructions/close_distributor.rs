@@ -1,9 +1,14 @@
 use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::CloseDistributorEvent, merkle_distributor::MerkleDistributor},
+    utils::transfer_from_vault,
+};
 
 // Accounts for [merkle_distributor::close_distributor].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseDistributor<'info> {
     /// [MerkleDistributor].
@@ -17,8 +22,8 @@ pub struct CloseDistributor<'info> {
     pub distributor: Account<'info, MerkleDistributor>,
 
     /// Clawback receiver token account
-    #[account(mut)]
-    pub token_vault: Account<'info, TokenAccount>,
+    #[account(mut, token::token_program = token_program)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin wallet, responsible for creating the distributor and paying for the transaction.
     /// Also has the authority to set the clawback receiver and change itself.
@@ -27,33 +32,33 @@ pub struct CloseDistributor<'info> {
 
     /// account receive token back
     #[account(mut)]
-    pub destination_token_account: Account<'info, TokenAccount>,
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// The [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[allow(clippy::result_large_err)]
-pub fn handle_close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
-    let distributor = &ctx.accounts.distributor;
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.token_vault.to_account_info(),
-                to: ctx.accounts.destination_token_account.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
+pub fn handle_close_distributor<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseDistributor<'info>>,
+) -> Result<()> {
+    transfer_from_vault(
+        &ctx.accounts.distributor,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
         ctx.accounts.token_vault.amount,
     )?;
+
+    emit_cpi!(CloseDistributorEvent {
+        distributor: ctx.accounts.distributor.key(),
+        destination: ctx.accounts.destination_token_account.key(),
+        amount: ctx.accounts.token_vault.amount,
+    });
     Ok(())
 }
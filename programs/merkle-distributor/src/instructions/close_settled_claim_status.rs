@@ -0,0 +1,82 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, claimed_event::ClaimStatusClosedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::close_settled_claim_status] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSettledClaimStatus<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    /// CHECK: deserialized in the handler, as it is left as a tombstone that is no longer a valid [ClaimStatus]
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_status: UncheckedAccount<'info>,
+
+    /// Who claimed the tokens, receives the reclaimed rent.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+}
+
+/// Closes a claimant's [ClaimStatus] once nothing is owed to them:
+/// 1. Shrinks claim_status to [ClaimStatus::TOMBSTONE_LEN], keeping it owned by the program so the
+///    claim cannot be made again with new_claim
+/// 2. Returns the rent no longer needed to the claimant
+/// 3. Increments num_nodes_settled by 1
+/// CHECK:
+///     1. claim_status is a [ClaimStatus] that was not closed yet
+///     2. The locked amount was fully withdrawn, which only happens once vesting ended
+///     3. The claimant paid the claim_status rent, a sponsored claim status is not refunded to them
+#[allow(clippy::result_large_err)]
+pub fn handle_close_settled_claim_status(ctx: Context<CloseSettledClaimStatus>) -> Result<()> {
+    let claim_status_info = ctx.accounts.claim_status.to_account_info();
+    {
+        let claim_status = Account::<ClaimStatus>::try_from(&claim_status_info)?;
+        require!(claim_status.is_settled(), ErrorCode::ClaimStatusNotSettled);
+        require!(!claim_status.rent_sponsored, ErrorCode::RentSponsored);
+    }
+
+    claim_status_info.realloc(ClaimStatus::TOMBSTONE_LEN, false)?;
+
+    let rent_reclaimed = claim_status_info
+        .lamports()
+        .checked_sub(Rent::get()?.minimum_balance(ClaimStatus::TOMBSTONE_LEN))
+        .ok_or(ErrorCode::ArithmeticError)?;
+    **claim_status_info.try_borrow_mut_lamports()? -= rent_reclaimed;
+    **ctx.accounts.claimant.try_borrow_mut_lamports()? += rent_reclaimed;
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.num_nodes_settled = distributor
+        .num_nodes_settled
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Closed settled claim, reclaimed {} lamports",
+        rent_reclaimed
+    );
+    emit_cpi!(ClaimStatusClosedEvent {
+        distributor: distributor.key(),
+        claimant: ctx.accounts.claimant.key(),
+        rent_reclaimed,
+    });
+
+    Ok(())
+}
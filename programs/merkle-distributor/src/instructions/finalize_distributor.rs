@@ -0,0 +1,112 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::DistributorFinalizedEvent,
+        distributor_metadata::DistributorMetadata,
+        merkle_distributor::{ClawbackMode, MerkleDistributor},
+    },
+    utils::{close_vault, transfer_from_vault},
+};
+
+/// [merkle_distributor::finalize_distributor] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeDistributor<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, close = admin)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account, receives any dust left in the vault.
+    #[account(mut, address = distributor.clawback_receiver)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer, receives the rent of the distributor, its vault and its metadata.
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [DistributorMetadata], if one was set, closed along with the distributor.
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            b"DistributorMetadata".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump = metadata.bump,
+    )]
+    pub metadata: Option<Account<'info, DistributorMetadata>>,
+}
+
+/// Winds down a distributor for good:
+/// 1. Transfers any dust left in the vault to the clawback receiver
+/// 2. Closes the vault, the distributor and its metadata if passed, returning their rent to the admin
+/// The version must not be reused afterwards, as ClaimStatus accounts of the closed distributor would
+/// apply to a new distributor at the same address.
+/// CHECK:
+///     1. The distributor has been clawed back and is not paused
+///     2. No locked tokens are still owed to claimants if the clawback preserved them
+///     3. A Token-2022 vault has no withheld transfer fees left, they must be harvested first
+#[allow(clippy::result_large_err)]
+pub fn handle_finalize_distributor<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeDistributor<'info>>,
+) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(distributor.clawed_back, ErrorCode::NotClawedBack);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        distributor.clawback_mode == ClawbackMode::Full
+            || distributor.total_locked_outstanding == 0,
+        ErrorCode::LockedAmountsOutstanding
+    );
+
+    let amount = ctx.accounts.from.amount;
+    if amount > 0 {
+        transfer_from_vault(
+            distributor,
+            &ctx.accounts.from,
+            &ctx.accounts.to,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    }
+
+    close_vault(
+        distributor,
+        &ctx.accounts.from,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.token_program,
+    )?;
+
+    // Note: might get truncated, do not rely on
+    msg!("Finalized distributor, swept {} tokens", amount);
+    emit_cpi!(DistributorFinalizedEvent {
+        distributor: distributor.key(),
+        clawback_receiver: ctx.accounts.to.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
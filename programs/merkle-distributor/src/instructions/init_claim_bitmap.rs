@@ -0,0 +1,74 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_bitmap::{ClaimBitmap, LEAVES_PER_CLAIM_BITMAP},
+        merkle_distributor::{ClaimMode, MerkleDistributor},
+    },
+};
+
+/// Accounts for [merkle_distributor::init_claim_bitmap].
+#[derive(Accounts)]
+#[instruction(bitmap_index: u64)]
+pub struct InitClaimBitmap<'info> {
+    /// [MerkleDistributor].
+    #[account(has_one = admin)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [ClaimBitmap] covering leaves of the distributor starting at bitmap_index * LEAVES_PER_CLAIM_BITMAP.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            bitmap_index.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimBitmap::LEN,
+        payer = admin
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Admin of the distributor, pays for the bitmap.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a [ClaimBitmap] for a bitmap mode [MerkleDistributor].
+/// A distributor with max_num_nodes leaves needs ceil(max_num_nodes / LEAVES_PER_CLAIM_BITMAP) bitmaps.
+/// CHECK:
+///     1. The distributor uses bitmap claims
+///     2. The bitmap covers at least one leaf of the distributor
+#[allow(clippy::result_large_err)]
+pub fn handle_init_claim_bitmap(ctx: Context<InitClaimBitmap>, bitmap_index: u64) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(
+        distributor.claim_mode == ClaimMode::Bitmap,
+        ErrorCode::InvalidClaimMode
+    );
+    require!(
+        bitmap_index
+            .checked_mul(LEAVES_PER_CLAIM_BITMAP)
+            .ok_or(ErrorCode::ArithmeticError)?
+            < distributor.max_num_nodes,
+        ErrorCode::InvalidLeafIndex
+    );
+
+    let mut claim_bitmap = ctx.accounts.claim_bitmap.load_init()?;
+    claim_bitmap.distributor = distributor.key();
+    claim_bitmap.index = bitmap_index;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created claim bitmap {} for distributor {}",
+        bitmap_index,
+        distributor.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::bpf_loader_upgradeable, system_program::System,
+    Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::SetCreatorsEvent, program_config::ProgramConfig},
+};
+
+/// [merkle_distributor::init_program_config] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitProgramConfig<'info> {
+    /// The [ProgramConfig].
+    #[account(
+        init,
+        seeds = [b"ProgramConfig".as_ref()],
+        bump,
+        space = ProgramConfig::LEN,
+        payer = authority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Data account of this program, holding its upgrade authority.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Upgrade authority of the program, pays for the [ProgramConfig].
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the [ProgramConfig] with the keys allowed to create distributors.
+/// CHECK:
+///     1. The authority is the upgrade authority of the program
+///     2. There are at most [ProgramConfig::MAX_CREATORS] creators
+#[allow(clippy::result_large_err)]
+pub fn handle_init_program_config(
+    ctx: Context<InitProgramConfig>,
+    creators: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        creators.len() <= ProgramConfig::MAX_CREATORS,
+        ErrorCode::TooManyCreators
+    );
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.bump = *ctx.bumps.get("program_config").unwrap();
+    program_config.creators = creators;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Initialized program config with {} creators",
+        program_config.creators.len()
+    );
+    emit_cpi!(SetCreatorsEvent {
+        program_config: program_config.key(),
+        creators: program_config.creators.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, System, Transfer},
+    Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::DistributorMigratedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::migrate_distributor] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateDistributor<'info> {
+    /// The [MerkleDistributor].
+    /// CHECK: deserialized in the handler, as a distributor in an older layout is too small to be an [Account]
+    #[account(mut, owner = crate::ID)]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Pays the rent for the grown account, anyone can migrate a distributor.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a distributor created with an older layout to [MerkleDistributor::LEN]:
+/// 1. Tops up its lamports to stay rent exempt, paid by the payer
+/// 2. Reallocs it, zero-initializing the fields appended since, which all default to their legacy behaviour
+/// 3. Sets layout_version to [MerkleDistributor::LAYOUT_VERSION]
+/// CHECK:
+///     1. distributor is a [MerkleDistributor]
+///     2. It is smaller than [MerkleDistributor::LEN]
+#[allow(clippy::result_large_err)]
+pub fn handle_migrate_distributor(ctx: Context<MigrateDistributor>) -> Result<()> {
+    let distributor_info = ctx.accounts.distributor.to_account_info();
+    require!(
+        distributor_info.data_len() < MerkleDistributor::LEN,
+        ErrorCode::AlreadyMigrated
+    );
+    let mut distributor =
+        MerkleDistributor::try_deserialize_any_layout(&distributor_info.try_borrow_data()?)?;
+
+    let rent_paid = Rent::get()?
+        .minimum_balance(MerkleDistributor::LEN)
+        .saturating_sub(distributor_info.lamports());
+    if rent_paid > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: distributor_info.clone(),
+                },
+            ),
+            rent_paid,
+        )?;
+    }
+
+    distributor_info.realloc(MerkleDistributor::LEN, true)?;
+
+    distributor.layout_version = MerkleDistributor::LAYOUT_VERSION;
+    distributor.try_serialize(&mut &mut distributor_info.try_borrow_mut_data()?[..])?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Migrated distributor to layout {}, paid {} lamports of rent",
+        distributor.layout_version,
+        rent_paid
+    );
+    emit_cpi!(DistributorMigratedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        layout_version: distributor.layout_version,
+        rent_paid,
+    });
+
+    Ok(())
+}
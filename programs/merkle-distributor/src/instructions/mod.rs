@@ -1,18 +1,60 @@
+pub use accept_admin::*;
+pub use batch_claim::*;
 pub use claim_locked::*;
+pub use claim_locked_to::*;
+pub use claim_locked_with_vesting::*;
 pub use clawback::*;
+pub use finalize_distributor::*;
+pub use init_claim_bitmap::*;
+pub use init_program_config::*;
+pub use migrate_distributor::*;
+pub use new_bitmap_claim::*;
 pub use new_claim::*;
+pub use new_claim_and_deposit::*;
+pub use new_claim_to::*;
+pub use new_claim_with_vesting::*;
 pub use new_distributor::*;
-pub use set_admin::*;
+pub use pause::*;
+pub use propose_admin::*;
+pub use set_claim_fee::*;
 pub use set_clawback_receiver::*;
+pub use set_creators::*;
+pub use set_deposit_program::*;
+pub use set_distributor_metadata::*;
 pub use set_enable_slot::*;
+pub use set_operator::*;
+pub use update_root::*;
+pub use withdraw_forgone::*;
+pub mod accept_admin;
+pub mod batch_claim;
 pub mod claim_locked;
+pub mod claim_locked_to;
+pub mod claim_locked_with_vesting;
 pub mod clawback;
 pub mod close_distributor;
+pub mod finalize_distributor;
+pub mod init_claim_bitmap;
+pub mod init_program_config;
+pub mod migrate_distributor;
+pub mod new_bitmap_claim;
 pub mod new_claim;
+pub mod new_claim_and_deposit;
+pub mod new_claim_to;
+pub mod new_claim_with_vesting;
 pub mod new_distributor;
-pub mod set_admin;
+pub mod pause;
+pub mod propose_admin;
+pub mod set_claim_fee;
 pub mod set_clawback_receiver;
+pub mod set_creators;
+pub mod set_deposit_program;
+pub mod set_distributor_metadata;
 pub mod set_enable_slot;
+pub mod set_operator;
+pub mod update_root;
+pub mod withdraw_forgone;
 pub use close_distributor::*;
+pub mod close_settled_claim_status;
+pub use close_settled_claim_status::*;
 pub mod close_claim_status;
 pub use close_claim_status::*;
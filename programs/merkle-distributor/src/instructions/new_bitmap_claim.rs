@@ -0,0 +1,209 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::hash::hashv, Accounts, Key, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::LEAF_PREFIX,
+    state::{
+        claim_bitmap::ClaimBitmap,
+        claim_status::ClaimStatus,
+        claimed_event::NewClaimEvent,
+        merkle_distributor::{ClaimMode, MerkleDistributor},
+    },
+    utils::transfer_from_vault,
+};
+
+/// [merkle_distributor::new_bitmap_claim] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(leaf_index: u64)]
+pub struct NewBitmapClaim<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// [ClaimBitmap] holding the bit for leaf_index.
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            ClaimBitmap::bitmap_index(leaf_index).to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(
+        mut,
+        token::mint=distributor.mint,
+        token::authority = claimant.key()
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claims a leaf of a bitmap mode [MerkleDistributor], recording it as a bit instead of a ClaimStatus.
+/// The unlocked amount claimed follows the same schedule as [crate::instructions::handle_new_claim],
+/// the remainder is forgone.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Sets the leaf's bit in the claim_bitmap
+/// 3. Transfers the claimable unlocked amount to the claimant
+/// 4. Increments total_amount_claimed and total_amount_forgone
+/// CHECK:
+///     1. The claim window has not expired, the claim end has not passed, the distributor has not been clawed back
+///        or paused and uses bitmap claims
+///     2. The leaf index is within max_num_nodes and has not been claimed
+///     3. Num nodes claimed is less than max_num_nodes
+///     4. The merkle proof is valid for the leaf index, claimant and amount
+#[allow(clippy::result_large_err)]
+pub fn handle_new_bitmap_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewBitmapClaim<'info>>,
+    leaf_index: u64,
+    amount_unlocked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+    let curr_slot = Clock::get()?.slot;
+
+    require!(
+        distributor.claim_mode == ClaimMode::Bitmap,
+        ErrorCode::InvalidClaimMode
+    );
+    require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+    require!(
+        distributor.enable_slot <= curr_slot,
+        ErrorCode::ClaimingIsNotStarted
+    );
+    require!(
+        distributor.start_ts <= curr_ts,
+        ErrorCode::ClaimingIsNotStarted
+    );
+    require!(
+        distributor.accepts_new_claims(curr_ts),
+        ErrorCode::ClaimWindowClosed
+    );
+    require!(
+        leaf_index < distributor.max_num_nodes,
+        ErrorCode::InvalidLeafIndex
+    );
+
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let claimant = &ctx.accounts.claimant;
+
+    // Verify the merkle proof.
+    let node = hashv(&[
+        &leaf_index.to_le_bytes(),
+        &claimant.key().to_bytes(),
+        &amount_unlocked.to_le_bytes(),
+    ]);
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    ctx.accounts
+        .claim_bitmap
+        .load_mut()?
+        .set_claimed(leaf_index)?;
+
+    // Same vesting math as a ClaimStatus claim, without persisting the ClaimStatus
+    let mut claim = ClaimStatus {
+        unlocked_amount: amount_unlocked,
+        ..ClaimStatus::default()
+    };
+    claim.update_unlocked_amount_claimed(
+        curr_ts,
+        distributor.start_ts,
+        distributor.end_ts,
+        distributor.get_start_claim_pct(),
+    )?;
+    let amount_claimed = claim.unlocked_amount_claimed;
+    let amount_forgone = claim.get_unlocked_amount_forgone()?;
+
+    let transfer_fee = transfer_from_vault(
+        distributor,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount_claimed,
+    )?;
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount_claimed)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    distributor.total_amount_forgone = distributor
+        .total_amount_forgone
+        .checked_add(amount_forgone)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed + distributor.total_amount_forgone
+            <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Claimed leaf {} with {} claimed and {} forgone",
+        leaf_index,
+        amount_claimed,
+        amount_forgone,
+    );
+    emit_cpi!(NewClaimEvent {
+        distributor: distributor.key(),
+        version: distributor.version,
+        claimant: claimant.key(),
+        destination: ctx.accounts.to.key(),
+        timestamp: curr_ts,
+        amount_claimed,
+        amount_forgone,
+        transfer_fee,
+        deposit_program: Pubkey::default(),
+        claim_fee: 0,
+        claim_fee_lamports: 0,
+    });
+
+    Ok(())
+}
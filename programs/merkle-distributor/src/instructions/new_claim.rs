@@ -1,27 +1,30 @@
 use anchor_lang::{
-    context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
-    Key, Result,
-};
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
+    context::Context,
+    prelude::*,
+    solana_program::hash::hashv,
+    system_program::{self, System, Transfer},
+    Accounts, Key, Result,
 };
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use jito_merkle_verify::verify;
 
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::NewClaimEvent,
-        merkle_distributor::MerkleDistributor,
+        claim_status::ClaimStatus,
+        claimed_event::NewClaimEvent,
+        merkle_distributor::{ClaimMode, MerkleDistributor},
     },
+    utils::transfer_from_vault,
 };
 
 // We need to discern between leaf and intermediate nodes to prevent trivial second
 // pre-image attacks.
 // https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
-const LEAF_PREFIX: &[u8] = &[0];
+pub(crate) const LEAF_PREFIX: &[u8] = &[0];
 
 /// [merkle_distributor::new_claim] accounts.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct NewClaim<'info> {
     /// The [MerkleDistributor].
@@ -38,18 +41,19 @@ pub struct NewClaim<'info> {
         ],
         bump,
         space = ClaimStatus::LEN,
-        payer = claimant
+        payer = payer
     )]
     pub claim_status: Account<'info, ClaimStatus>,
 
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
         address = distributor.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
     #[account(
@@ -57,49 +61,150 @@ pub struct NewClaim<'info> {
         token::mint=distributor.mint,
         token::authority = claimant.key()
     )]
-    pub to: Account<'info, TokenAccount>,
+    pub to: InterfaceAccount<'info, TokenAccount>,
 
     /// Who is claiming the tokens.
-    #[account(mut, address = to.owner @ ErrorCode::OwnerMismatch)]
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
     pub claimant: Signer<'info>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// Pays the rent for the claim status PDA, can be the claimant or a relayer sponsoring the claim.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The [System] program.
     pub system_program: Program<'info, System>,
+
+    /// Receives the claim fee, required if the distributor charges one.
+    /// CHECK: checked against the distributor's claim_fee_receiver when charging the fee
+    #[account(mut)]
+    pub claim_fee_receiver: Option<UncheckedAccount<'info>>,
+}
+
+/// Accounts a new claim pays the claim fee of its [MerkleDistributor] with.
+pub(crate) struct ClaimFeeAccounts<'a, 'info> {
+    /// Receives the fee, the distributor's claim_fee_receiver.
+    pub receiver: &'a AccountInfo<'info>,
+    /// Pays a lamport fee.
+    pub payer: &'a Signer<'info>,
+    /// The [System] program.
+    pub system_program: &'a Program<'info, System>,
+}
+
+impl<'a, 'info> ClaimFeeAccounts<'a, 'info> {
+    /// Accounts paying the claim fee, if a claim fee receiver was passed.
+    pub(crate) fn new(
+        receiver: &'a Option<UncheckedAccount<'info>>,
+        payer: &'a Signer<'info>,
+        system_program: &'a Program<'info, System>,
+    ) -> Option<Self> {
+        receiver.as_ref().map(|receiver| ClaimFeeAccounts {
+            receiver,
+            payer,
+            system_program,
+        })
+    }
 }
 
 /// Initializes a new claim from the [MerkleDistributor].
 /// 1. Increments num_nodes_claimed by 1
-/// 2. Initializes claim_status
-/// 3. Transfers claim_status.unlocked_amount to the claimant
-/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// 2. Initializes claim_status, with rent paid by the payer
+/// 3. Transfers claim_status.unlocked_amount_claimed to the claimant, less any basis point claim fee which
+///    goes to the claim fee receiver, and the payer sends any lamport claim fee to it
+/// 4. Increments total_amount_claimed by the amount sent to the claimant and total_claim_fee_amount by the
+///    basis point claim fee, both including any transfer fee withheld by a Token-2022 mint since it leaves
+///    the vault all the same
+/// 5. Increments total_locked_outstanding by the locked amount
 /// CHECK:
-///     1. The claim window has not expired and the distributor has not been clawed back
+///     1. The claim window has not expired, the claim end has not passed, the distributor has not been clawed
+///        back or paused and uses ClaimStatus accounts with the distributor's vesting schedule
 ///     2. The claimant is the owner of the to account
 ///     3. Num nodes claimed is less than max_num_nodes
 ///     4. The merkle proof is valid
+///     5. The claim fee receiver is the distributor's, if it charges a fee
 #[allow(clippy::result_large_err)]
-pub fn handle_new_claim(
-    ctx: Context<NewClaim>,
+pub fn handle_new_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
     amount_unlocked: u64,
     amount_locked: u64,
     proof: Vec<[u8; 32]>,
 ) -> Result<()> {
-    let distributor = &mut ctx.accounts.distributor;
+    let claim_fee_accounts = ClaimFeeAccounts::new(
+        &ctx.accounts.claim_fee_receiver,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    );
+    let event = execute_new_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.payer,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount_unlocked,
+        amount_locked,
+        None,
+        claim_fee_accounts,
+        proof,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
 
+/// Shared by [handle_new_claim], [crate::instructions::handle_new_claim_to] and
+/// [crate::instructions::handle_new_claim_with_vesting].
+/// `vesting` is the claimant's own (start_ts, end_ts), required if the distributor uses per-leaf vesting.
+/// `payer` is who paid the claim status rent, the claimant or a sponsor.
+/// `claim_fee_accounts` are required if the distributor charges a claim fee.
+/// Returns the [NewClaimEvent] for the handler to emit, as emit_cpi! needs its Context.
+#[allow(clippy::result_large_err)]
+pub(crate) fn execute_new_claim<'info>(
+    distributor: &mut Account<'info, MerkleDistributor>,
+    claim_status: &mut Account<'info, ClaimStatus>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    claimant: &Signer<'info>,
+    payer: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    vesting: Option<(i64, i64)>,
+    claim_fee_accounts: Option<ClaimFeeAccounts<'_, 'info>>,
+    proof: Vec<[u8; 32]>,
+) -> Result<NewClaimEvent> {
     let curr_ts = Clock::get()?.unix_timestamp;
     let curr_slot = Clock::get()?.slot;
 
+    require!(
+        distributor.claim_mode == ClaimMode::ClaimStatus,
+        ErrorCode::InvalidClaimMode
+    );
+    require!(
+        vesting.is_some() == distributor.leaf_vesting,
+        ErrorCode::LeafVestingMismatch
+    );
+    let (start_ts, end_ts) = vesting.unwrap_or((distributor.start_ts, distributor.end_ts));
+
     require!(!distributor.clawed_back, ErrorCode::ClaimExpired);
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
     require!(
         distributor.enable_slot <= curr_slot,
         ErrorCode::ClaimingIsNotStarted
     );
+    require!(start_ts <= curr_ts, ErrorCode::ClaimingIsNotStarted);
     require!(
-        distributor.start_ts <= curr_ts,
-        ErrorCode::ClaimingIsNotStarted
+        distributor.accepts_new_claims(curr_ts),
+        ErrorCode::ClaimWindowClosed
     );
 
     distributor.num_nodes_claimed = distributor
@@ -112,64 +217,79 @@ pub fn handle_new_claim(
         ErrorCode::MaxNodesExceeded
     );
 
-    let claimant_account = &ctx.accounts.claimant;
-
     // Verify the merkle proof.
-    let node = hashv(&[
-        &claimant_account.key().to_bytes(),
-        &amount_unlocked.to_le_bytes(),
-        &amount_locked.to_le_bytes(),
-    ]);
-
-    let distributor_key = ctx.accounts.distributor.key();
-    let distributor = &ctx.accounts.distributor;
-    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+    let node = hash_leaf(&claimant.key(), amount_unlocked, amount_locked, vesting);
 
     require!(
-        verify(proof, distributor.root, node.to_bytes()),
+        verify(proof, distributor.root, node),
         ErrorCode::InvalidProof
     );
 
-    let claim_status = &mut ctx.accounts.claim_status;
-
     // Seed initial values
-    claim_status.claimant = claimant_account.key();
+    claim_status.claimant = claimant.key();
     claim_status.locked_amount = amount_locked;
     claim_status.locked_amount_withdrawn = 0;
     claim_status.unlocked_amount = amount_unlocked;
     claim_status.closable = distributor.closable;
-    claim_status.distributor = distributor_key;
+    claim_status.distributor = distributor.key();
+    claim_status.rent_sponsored = payer.key() != claimant.key();
     claim_status.update_unlocked_amount_claimed(
         curr_ts,
-        distributor.start_ts,
-        distributor.end_ts,
+        start_ts,
+        end_ts,
+        distributor.get_start_claim_pct(),
     )?;
     let amount_forgone = claim_status.get_unlocked_amount_forgone()?;
 
-    let seeds = [
-        b"MerkleDistributor".as_ref(),
-        &distributor.mint.to_bytes(),
-        &distributor.version.to_le_bytes(),
-        &[ctx.accounts.distributor.bump],
-    ];
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        claim_status.unlocked_amount_claimed,
-    )?;
+    let claim_fee = distributor.get_claim_fee_amount(claim_status.unlocked_amount_claimed);
+    let claim_fee_lamports = distributor.claim_fee_lamports;
+    let amount_claimed = claim_status
+        .unlocked_amount_claimed
+        .checked_sub(claim_fee)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let transfer_fee =
+        transfer_from_vault(distributor, from, to, mint, token_program, amount_claimed)?;
+
+    if distributor.has_claim_fee() {
+        let claim_fee_accounts = claim_fee_accounts.ok_or(ErrorCode::InvalidClaimFeeReceiver)?;
+        require_keys_eq!(
+            claim_fee_accounts.receiver.key(),
+            distributor.claim_fee_receiver,
+            ErrorCode::InvalidClaimFeeReceiver
+        );
+
+        if claim_fee > 0 {
+            let treasury = InterfaceAccount::<TokenAccount>::try_from(claim_fee_accounts.receiver)?;
+            transfer_from_vault(distributor, from, &treasury, mint, token_program, claim_fee)?;
+        }
+        if claim_fee_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    claim_fee_accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: claim_fee_accounts.payer.to_account_info(),
+                        to: claim_fee_accounts.receiver.clone(),
+                    },
+                ),
+                claim_fee_lamports,
+            )?;
+        }
+    }
 
-    let distributor = &mut ctx.accounts.distributor;
     distributor.total_amount_claimed = distributor
         .total_amount_claimed
-        .checked_add(claim_status.unlocked_amount_claimed)
+        .checked_add(amount_claimed)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    distributor.total_claim_fee_amount = distributor
+        .total_claim_fee_amount
+        .checked_add(claim_fee)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    distributor.total_claim_fee_lamports = distributor
+        .total_claim_fee_lamports
+        .checked_add(claim_fee_lamports)
         .ok_or(ErrorCode::ArithmeticError)?;
 
     distributor.total_amount_forgone = distributor
@@ -177,24 +297,64 @@ pub fn handle_new_claim(
         .checked_add(amount_forgone)
         .ok_or(ErrorCode::ArithmeticError)?;
 
+    distributor.total_locked_outstanding = distributor
+        .total_locked_outstanding
+        .checked_add(amount_locked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
     require!(
-        distributor.total_amount_claimed + distributor.total_amount_forgone
+        distributor.total_amount_claimed
+            + distributor.total_amount_forgone
+            + distributor.total_claim_fee_amount
             <= distributor.max_total_claim,
         ErrorCode::ExceededMaxClaim
     );
 
     // Note: might get truncated, do not rely on
     msg!(
-        "Created new claim with {} claimed and {} forgone",
-        claim_status.unlocked_amount_claimed,
+        "Created new claim with {} claimed, {} forgone and {} claim fee",
+        amount_claimed,
         amount_forgone,
+        claim_fee,
     );
-    emit!(NewClaimEvent {
-        claimant: claimant_account.key(),
+
+    Ok(NewClaimEvent {
+        distributor: distributor.key(),
+        version: distributor.version,
+        claimant: claimant.key(),
+        destination: to.key(),
         timestamp: curr_ts,
-        amount_claimed: claim_status.unlocked_amount_claimed,
+        amount_claimed,
         amount_forgone: amount_forgone,
-    });
+        transfer_fee,
+        deposit_program: Pubkey::default(),
+        claim_fee,
+        claim_fee_lamports,
+    })
+}
 
-    Ok(())
+/// Leaf of a [ClaimMode::ClaimStatus] distributor, prefixed to tell it apart from intermediate nodes.
+/// Also commits to the claimant's own vesting schedule for distributors using per-leaf vesting.
+pub(crate) fn hash_leaf(
+    claimant: &Pubkey,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    vesting: Option<(i64, i64)>,
+) -> [u8; 32] {
+    let node = match vesting {
+        Some((start_ts, end_ts)) => hashv(&[
+            &claimant.to_bytes(),
+            &amount_unlocked.to_le_bytes(),
+            &amount_locked.to_le_bytes(),
+            &start_ts.to_le_bytes(),
+            &end_ts.to_le_bytes(),
+        ]),
+        None => hashv(&[
+            &claimant.to_bytes(),
+            &amount_unlocked.to_le_bytes(),
+            &amount_locked.to_le_bytes(),
+        ]),
+    };
+
+    hashv(&[LEAF_PREFIX, &node.to_bytes()]).to_bytes()
 }
@@ -0,0 +1,163 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke},
+    system_program::System,
+    Accounts, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    instructions::new_claim::{execute_new_claim, ClaimFeeAccounts},
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::new_claim_and_deposit] accounts.
+/// The accounts of the deposit instruction follow as remaining accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct NewClaimAndDeposit<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = payer
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to, which they are deposited from.
+    #[account(
+        mut,
+        token::mint=distributor.mint,
+        token::authority = claimant.key()
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens, and authorizes the deposit.
+    #[account(address = to.owner @ ErrorCode::OwnerMismatch)]
+    pub claimant: Signer<'info>,
+
+    /// Pays the rent for the claim status PDA, can be the claimant or a relayer sponsoring the claim.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// Program allowlisted by the admin to deposit the claimed tokens into.
+    /// CHECK: address checked against the distributor, which only allows executable programs
+    #[account(
+        executable,
+        address = distributor.deposit_program @ ErrorCode::InvalidDepositProgram,
+        constraint = distributor.deposit_program != Pubkey::default() @ ErrorCode::InvalidDepositProgram
+    )]
+    pub deposit_program: UncheckedAccount<'info>,
+
+    /// Receives the claim fee, required if the distributor charges one.
+    /// CHECK: checked against the distributor's claim_fee_receiver when charging the fee
+    #[account(mut)]
+    pub claim_fee_receiver: Option<UncheckedAccount<'info>>,
+}
+
+/// Initializes a new claim from the [MerkleDistributor] like [crate::instructions::handle_new_claim], then
+/// deposits the tokens received into the deposit program in the same instruction.
+/// The deposit instruction data is `deposit_data` followed by the amount received as a little endian u64,
+/// net of any Token-2022 transfer fee, and its accounts are the remaining accounts.
+/// Only the claimant's signature is passed on, the distributor does not sign the deposit.
+/// CHECK:
+///     1. Everything new_claim checks
+///     2. The deposit program is the one allowlisted on the distributor
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_and_deposit<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaimAndDeposit<'info>>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+    deposit_data: Vec<u8>,
+) -> Result<()> {
+    let claim_fee_accounts = ClaimFeeAccounts::new(
+        &ctx.accounts.claim_fee_receiver,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    );
+    let mut event = execute_new_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.payer,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount_unlocked,
+        amount_locked,
+        None,
+        claim_fee_accounts,
+        proof,
+    )?;
+
+    let amount_received = event
+        .amount_claimed
+        .checked_sub(event.transfer_fee)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let mut data = deposit_data;
+    data.extend_from_slice(&amount_received.to_le_bytes());
+    let deposit_ix = Instruction {
+        program_id: ctx.accounts.deposit_program.key(),
+        accounts: ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data,
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.deposit_program.to_account_info());
+    invoke(&deposit_ix, &account_infos)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Deposited {} into {}",
+        amount_received,
+        ctx.accounts.deposit_program.key()
+    );
+
+    event.deposit_program = ctx.accounts.deposit_program.key();
+    emit_cpi!(event);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    instructions::new_claim::{execute_new_claim, ClaimFeeAccounts},
+    state::{claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::new_claim_to] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct NewClaimTo<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = ClaimStatus::LEN,
+        payer = payer
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    /// Any token account of the distributor mint, chosen by the claimant
+    #[account(mut, token::mint = distributor.mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Who is claiming the tokens.
+    pub claimant: Signer<'info>,
+
+    /// Pays the rent for the claim status PDA, can be the claimant or a relayer sponsoring the claim.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// Receives the claim fee, required if the distributor charges one.
+    /// CHECK: checked against the distributor's claim_fee_receiver when charging the fee
+    #[account(mut)]
+    pub claim_fee_receiver: Option<UncheckedAccount<'info>>,
+}
+
+/// Initializes a new claim from the [MerkleDistributor], sending the unlocked tokens to a token
+/// account picked by the claimant instead of one they own.
+/// Same as [crate::instructions::handle_new_claim] otherwise.
+/// CHECK:
+///     1. The claim window has not expired and the distributor has not been clawed back
+///     2. The to account holds the distributor mint
+///     3. Num nodes claimed is less than max_num_nodes
+///     4. The merkle proof is valid for the claimant
+///     5. The claim fee receiver is the distributor's, if it charges a fee
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_to<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaimTo<'info>>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let claim_fee_accounts = ClaimFeeAccounts::new(
+        &ctx.accounts.claim_fee_receiver,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    );
+    let event = execute_new_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.payer,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount_unlocked,
+        amount_locked,
+        None,
+        claim_fee_accounts,
+        proof,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::{context::Context, prelude::*, Result};
+
+use crate::instructions::{execute_new_claim, new_claim::ClaimFeeAccounts, NewClaim};
+
+/// Initializes a new claim from a [crate::state::merkle_distributor::MerkleDistributor] whose
+/// leaves commit to the claimant's own vesting schedule, used instead of the distributor's
+/// start_ts and end_ts for this claimant.
+/// Same as [crate::instructions::handle_new_claim] otherwise.
+/// CHECK:
+///     1. The claim window has not expired, the distributor has not been clawed back and uses
+///        per-leaf vesting
+///     2. The claimant's start_ts has passed
+///     3. The claimant is the owner of the to account
+///     4. Num nodes claimed is less than max_num_nodes
+///     5. The merkle proof is valid for the amounts and vesting schedule
+///     6. The claim fee receiver is the distributor's, if it charges a fee
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_with_vesting<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    start_ts: i64,
+    end_ts: i64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let claim_fee_accounts = ClaimFeeAccounts::new(
+        &ctx.accounts.claim_fee_receiver,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    );
+    let event = execute_new_claim(
+        &mut ctx.accounts.distributor,
+        &mut ctx.accounts.claim_status,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.claimant,
+        &ctx.accounts.payer,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount_unlocked,
+        amount_locked,
+        Some((start_ts, end_ts)),
+        claim_fee_accounts,
+        proof,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
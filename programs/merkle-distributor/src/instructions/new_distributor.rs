@@ -1,24 +1,26 @@
 use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::NewDistributorEvent,
+        claim_status::PCT_PRECISION,
+        merkle_distributor::{ClaimMode, ClawbackMode, MerkleDistributor},
+        program_config::ProgramConfig,
+    },
+    utils::{require_no_transfer_hook, transfer_to_vault},
+};
 
 const SECONDS_PER_HOUR: i64 = 3600; // 60 minutes * 60 seconds
 const HOURS_PER_DAY: i64 = 24;
 const SECONDS_PER_DAY: i64 = SECONDS_PER_HOUR * HOURS_PER_DAY; // 24 hours * 3600 seconds
 
-mod admin {
-    use solana_program::declare_id;
-    #[cfg(feature = "mainnet-beta")]
-    declare_id!("2xN6e9Z7qT6KPAWSMNKt2WPDbeCcSDADdXqUXsh3UVfK");
-    #[cfg(not(feature = "mainnet-beta"))]
-    declare_id!("E7iAhFMa9KvwhJtaPpoqJE4wPZb16zj2Az7PM9YdWK15");
-}
-
 /// Accounts for [merkle_distributor::handle_new_distributor].
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(version: u64)]
 pub struct NewDistributor<'info> {
@@ -27,6 +29,7 @@ pub struct NewDistributor<'info> {
         init,
         seeds = [
             b"MerkleDistributor".as_ref(),
+            admin.key().to_bytes().as_ref(),
             mint.key().to_bytes().as_ref(),
             version.to_le_bytes().as_ref()
         ],
@@ -38,49 +41,77 @@ pub struct NewDistributor<'info> {
 
     /// Clawback receiver token account
     #[account(mut, token::mint = mint)]
-    pub clawback_receiver: Account<'info, TokenAccount>,
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
 
     /// The mint to distribute.
-    pub mint: Account<'info, Mint>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// Token vault
     /// Should create previously
     #[account(
-        associated_token::mint = mint,
-        associated_token::authority=distributor,
+        mut,
+        token::mint = mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+        address = get_associated_token_address_with_program_id(
+            &distributor.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin token account funding the vault with max_total_claim, which must then be funded separately if omitted.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program,
+    )]
+    pub funder: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Admin wallet, responsible for creating the distributor and paying for the transaction.
     /// Also has the authority to set the clawback receiver and change itself.
     #[account(
         mut,
-        address = admin::id()
+        constraint = program_config.is_creator(admin.key) @ ErrorCode::Unauthorized
     )]
     pub admin: Signer<'info>,
 
+    /// The [ProgramConfig] listing who may create distributors.
+    #[account(seeds = [b"ProgramConfig".as_ref()], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
     /// The [System] program.
     pub system_program: Program<'info, System>,
 
     /// The [Associated Token] program.
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    /// The [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-/// Creates a new [MerkleDistributor].
-/// After creating this [MerkleDistributor],
-/// the token_vault should be seeded with max_total_claim tokens.
+/// Creates a new [MerkleDistributor], for one of the creators listed in the [ProgramConfig].
+/// If a funder is passed, max_total_claim tokens are transferred from it into the token_vault in the same instruction,
+/// otherwise the token_vault should be seeded with max_total_claim tokens after creating this [MerkleDistributor].
 /// CHECK:
 ///     1. The start timestamp is before the end timestamp
 ///     2. The clawback timestamp is after the end timestamp
 ///     3. The start, end, and clawback_start timestamps are all in the future
 ///     4. The clawback start is at least one day after end timestamp
+///     5. The start claim pct is within [0, PCT_PRECISION]
+///     6. The cliff, if set, is between the start and end timestamps
+///     7. The unlock period is within [0, end - start]
+///     8. Per-leaf vesting is only used with ClaimStatus accounts, bitmap claims have nothing to vest
+///     9. The claim end, if set, is in the future and no later than the clawback start
+///     10. The funder, if passed, holds enough for the vault to receive max_total_claim after any transfer fee
+///     11. The mint has no transfer hook
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::result_large_err)]
-pub fn handle_new_distributor(
-    ctx: Context<NewDistributor>,
+pub fn handle_new_distributor<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewDistributor<'info>>,
     version: u64,
     root: [u8; 32],
     max_total_claim: u64,
@@ -90,6 +121,13 @@ pub fn handle_new_distributor(
     clawback_start_ts: i64,
     enable_slot: u64,
     closable: bool,
+    start_claim_pct: u64,
+    claim_mode: ClaimMode,
+    cliff_ts: i64,
+    unlock_period: i64,
+    leaf_vesting: bool,
+    clawback_mode: ClawbackMode,
+    claim_end_ts: i64,
 ) -> Result<()> {
     let curr_ts = Clock::get()?.unix_timestamp;
 
@@ -117,6 +155,32 @@ pub fn handle_new_distributor(
         ErrorCode::InsufficientClawbackDelay
     );
 
+    require!(
+        start_claim_pct as u128 <= PCT_PRECISION,
+        ErrorCode::InvalidStartClaimPct
+    );
+
+    require!(
+        cliff_ts == 0 || (start_vesting_ts <= cliff_ts && cliff_ts <= end_vesting_ts),
+        ErrorCode::InvalidCliff
+    );
+    require!(
+        unlock_period >= 0 && unlock_period <= end_vesting_ts - start_vesting_ts,
+        ErrorCode::InvalidUnlockPeriod
+    );
+
+    require!(
+        !leaf_vesting || claim_mode == ClaimMode::ClaimStatus,
+        ErrorCode::InvalidClaimMode
+    );
+
+    require!(
+        claim_end_ts == 0 || (curr_ts < claim_end_ts && claim_end_ts <= clawback_start_ts),
+        ErrorCode::InvalidClaimEndTs
+    );
+
+    require_no_transfer_hook(&ctx.accounts.mint.to_account_info())?;
+
     let distributor = &mut ctx.accounts.distributor;
 
     distributor.bump = *ctx.bumps.get("distributor").unwrap();
@@ -133,13 +197,39 @@ pub fn handle_new_distributor(
     distributor.clawback_start_ts = clawback_start_ts;
     distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
     distributor.admin = ctx.accounts.admin.key();
+    distributor.base = ctx.accounts.admin.key();
+    distributor.pending_admin = Pubkey::default();
+    distributor.operator = Pubkey::default();
     distributor.clawed_back = false;
+    distributor.paused = false;
     distributor.enable_slot = enable_slot;
     distributor.closable = closable;
+    distributor.start_claim_pct = start_claim_pct;
+    distributor.start_claim_pct_set = true;
+    distributor.claim_mode = claim_mode;
+    distributor.cliff_ts = cliff_ts;
+    distributor.unlock_period = unlock_period;
+    distributor.leaf_vesting = leaf_vesting;
+    distributor.clawback_mode = clawback_mode;
+    distributor.claim_end_ts = claim_end_ts;
+    distributor.total_locked_outstanding = 0;
+    distributor.layout_version = MerkleDistributor::LAYOUT_VERSION;
+
+    let amount_funded = match &ctx.accounts.funder {
+        Some(funder) => transfer_to_vault(
+            funder,
+            &ctx.accounts.token_vault,
+            &ctx.accounts.admin,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            max_total_claim,
+        )?,
+        None => 0,
+    };
 
     // Note: might get truncated, do not rely on
     msg! {
-        "New distributor created with version = {}, mint={}, vault={} max_total_claim={}, max_nodes: {}, start_ts: {}, end_ts: {}, clawback_start: {}, clawback_receiver: {} enable_slot {}",
+        "New distributor created with version = {}, mint={}, vault={} max_total_claim={}, max_nodes: {}, start_ts: {}, end_ts: {}, clawback_start: {}, clawback_receiver: {} enable_slot {} start_claim_pct {} claim_mode {:?} cliff_ts {} unlock_period {} leaf_vesting {} clawback_mode {:?} claim_end_ts {}",
             distributor.version,
             distributor.mint,
             ctx.accounts.token_vault.key(),
@@ -150,7 +240,30 @@ pub fn handle_new_distributor(
             distributor.clawback_start_ts,
             distributor.clawback_receiver,
             distributor.enable_slot,
+            distributor.start_claim_pct,
+            distributor.claim_mode,
+            distributor.cliff_ts,
+            distributor.unlock_period,
+            distributor.leaf_vesting,
+            distributor.clawback_mode,
+            distributor.claim_end_ts,
     };
+    emit_cpi!(NewDistributorEvent {
+        distributor: distributor.key(),
+        version: distributor.version,
+        mint: distributor.mint,
+        admin: distributor.admin,
+        clawback_receiver: distributor.clawback_receiver,
+        root: distributor.root,
+        max_total_claim: distributor.max_total_claim,
+        max_num_nodes: distributor.max_num_nodes,
+        start_ts: distributor.start_ts,
+        end_ts: distributor.end_ts,
+        clawback_start_ts: distributor.clawback_start_ts,
+        enable_slot: distributor.enable_slot,
+        amount_funded,
+        claim_end_ts: distributor.claim_end_ts,
+    });
 
     Ok(())
 }
@@ -0,0 +1,64 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::{PausedEvent, UnpausedEvent},
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::pause] and [merkle_distributor::unpause] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin or operator signer
+    #[account(constraint = distributor.is_admin_or_operator(authority.key) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+}
+
+/// Pauses new claims, locked withdrawals and clawback.
+/// CHECK:
+///     1. The distributor is not already paused
+#[allow(clippy::result_large_err)]
+pub fn handle_pause(ctx: Context<SetPaused>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(!distributor.paused, ErrorCode::DistributorPaused);
+
+    distributor.paused = true;
+
+    // Note: might get truncated, do not rely on
+    msg!("Paused distributor {}", distributor.key());
+    emit_cpi!(PausedEvent {
+        distributor: distributor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Resumes a paused distributor.
+/// CHECK:
+///     1. The distributor is paused
+#[allow(clippy::result_large_err)]
+pub fn handle_unpause(ctx: Context<SetPaused>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(distributor.paused, ErrorCode::DistributorNotPaused);
+
+    distributor.paused = false;
+
+    // Note: might get truncated, do not rely on
+    msg!("Unpaused distributor {}", distributor.key());
+    emit_cpi!(UnpausedEvent {
+        distributor: distributor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
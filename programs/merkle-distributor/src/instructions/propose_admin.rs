@@ -0,0 +1,54 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::AdminProposedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::propose_admin] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// Proposed admin account
+    /// CHECK: this can be any account, it must sign accept_admin to become admin
+    pub new_admin: AccountInfo<'info>,
+}
+
+/// Proposes a new admin, who only takes over once they accept.
+/// Proposing again replaces the pending admin, the default pubkey cancels the proposal.
+/// CHECK:
+///     1. The proposed admin is not the current one
+#[allow(clippy::result_large_err)]
+pub fn handle_propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        ctx.accounts.admin.key != &ctx.accounts.new_admin.key(),
+        ErrorCode::SameAdmin
+    );
+
+    distributor.pending_admin = ctx.accounts.new_admin.key();
+
+    // Note: might get truncated, do not rely on
+    msg!("proposed new admin {}", distributor.pending_admin);
+    emit_cpi!(AdminProposedEvent {
+        distributor: distributor.key(),
+        admin: distributor.admin,
+        pending_admin: distributor.pending_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::SetClaimFeeEvent,
+        merkle_distributor::{ClaimMode, MerkleDistributor},
+    },
+};
+
+/// [merkle_distributor::set_claim_fee] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimFee<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// New claim fee receiver
+    /// CHECK: any account for a lamport fee, a token account of the mint for a basis point fee, ignored without a fee
+    pub new_claim_fee_receiver: AccountInfo<'info>,
+}
+
+/// Sets the fee charged on each new claim, either `claim_fee_lamports` paid by the payer of the claim or
+/// a `claim_fee_bps` cut of its unlocked amount, both sent to the new claim fee receiver.
+/// Setting both to 0 removes the fee. Claims already created are not affected.
+/// CHECK:
+///     1. At most one of claim_fee_lamports and claim_fee_bps is set
+///     2. claim_fee_bps is at most [MerkleDistributor::MAX_CLAIM_FEE_BPS]
+///     3. The distributor uses ClaimStatus accounts, as new_bitmap_claim doesn't charge the fee
+///     4. The receiver of a basis point fee is a token account of the mint
+#[allow(clippy::result_large_err)]
+pub fn handle_set_claim_fee(
+    ctx: Context<SetClaimFee>,
+    claim_fee_lamports: u64,
+    claim_fee_bps: u16,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        (claim_fee_lamports == 0 || claim_fee_bps == 0)
+            && claim_fee_bps <= MerkleDistributor::MAX_CLAIM_FEE_BPS,
+        ErrorCode::InvalidClaimFee
+    );
+
+    let receiver = &ctx.accounts.new_claim_fee_receiver;
+    if claim_fee_lamports > 0 || claim_fee_bps > 0 {
+        require!(
+            distributor.claim_mode == ClaimMode::ClaimStatus,
+            ErrorCode::InvalidClaimFee
+        );
+    }
+    if claim_fee_bps > 0 {
+        let treasury = InterfaceAccount::<TokenAccount>::try_from(receiver)
+            .map_err(|_| ErrorCode::InvalidClaimFeeReceiver)?;
+        require_keys_eq!(
+            treasury.mint,
+            distributor.mint,
+            ErrorCode::InvalidClaimFeeReceiver
+        );
+    }
+
+    distributor.claim_fee_lamports = claim_fee_lamports;
+    distributor.claim_fee_bps = claim_fee_bps;
+    distributor.claim_fee_receiver = if distributor.has_claim_fee() {
+        receiver.key()
+    } else {
+        Pubkey::default()
+    };
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "set claim fee to {} lamports and {} bps",
+        distributor.claim_fee_lamports,
+        distributor.claim_fee_bps
+    );
+    emit_cpi!(SetClaimFeeEvent {
+        distributor: distributor.key(),
+        claim_fee_receiver: distributor.claim_fee_receiver,
+        claim_fee_lamports: distributor.claim_fee_lamports,
+        claim_fee_bps: distributor.claim_fee_bps,
+    });
+
+    Ok(())
+}
@@ -4,11 +4,15 @@ use anchor_lang::{
     prelude::*,
     Accounts, Result, ToAccountInfo,
 };
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::SetClawbackReceiverEvent, merkle_distributor::MerkleDistributor},
+};
 
 /// [merkle_distributor::set_clawback_receiver] accounts.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetClawbackReceiver<'info> {
     /// The [MerkleDistributor].
@@ -16,8 +20,8 @@ pub struct SetClawbackReceiver<'info> {
     pub distributor: Account<'info, MerkleDistributor>,
 
     /// New clawback account
-    #[account(token::mint=distributor.mint)]
-    pub new_clawback_account: Account<'info, TokenAccount>,
+    #[account(token::mint = distributor.mint)]
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin signer
     #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
@@ -38,6 +42,7 @@ pub fn handle_set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result
 
     let new_clawback_account = *ctx.accounts.new_clawback_account.to_account_info().key;
 
+    let old_clawback_receiver = distributor.clawback_receiver;
     distributor.clawback_receiver = new_clawback_account;
 
     // Note: might get truncated, do not rely on
@@ -46,6 +51,11 @@ pub fn handle_set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result
         new_clawback_account,
         ctx.accounts.new_clawback_account.owner
     );
+    emit_cpi!(SetClawbackReceiverEvent {
+        distributor: distributor.key(),
+        old_clawback_receiver,
+        new_clawback_receiver: new_clawback_account,
+    });
 
     Ok(())
 }
@@ -0,0 +1,57 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::bpf_loader_upgradeable, Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::SetCreatorsEvent, program_config::ProgramConfig},
+};
+
+/// [merkle_distributor::set_creators] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCreators<'info> {
+    /// The [ProgramConfig].
+    #[account(
+        mut,
+        seeds = [b"ProgramConfig".as_ref()],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Data account of this program, holding its upgrade authority.
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Upgrade authority of the program.
+    pub authority: Signer<'info>,
+}
+
+/// Replaces the keys allowed to create distributors. Existing distributors are not affected.
+/// CHECK:
+///     1. The authority is the upgrade authority of the program
+///     2. There are at most [ProgramConfig::MAX_CREATORS] creators
+#[allow(clippy::result_large_err)]
+pub fn handle_set_creators(ctx: Context<SetCreators>, creators: Vec<Pubkey>) -> Result<()> {
+    require!(
+        creators.len() <= ProgramConfig::MAX_CREATORS,
+        ErrorCode::TooManyCreators
+    );
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.creators = creators;
+
+    // Note: might get truncated, do not rely on
+    msg!("Set {} creators", program_config.creators.len());
+    emit_cpi!(SetCreatorsEvent {
+        program_config: program_config.key(),
+        creators: program_config.creators.clone(),
+    });
+
+    Ok(())
+}
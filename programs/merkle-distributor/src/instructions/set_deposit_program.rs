@@ -0,0 +1,55 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::SetDepositProgramEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::set_deposit_program] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDepositProgram<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// New deposit program
+    /// CHECK: must be executable, the default pubkey disables new_claim_and_deposit
+    pub new_deposit_program: AccountInfo<'info>,
+}
+
+/// Sets the program new_claim_and_deposit may send claimed tokens to.
+/// CHECK:
+///     1. The new deposit program is executable, unless it is the default pubkey
+#[allow(clippy::result_large_err)]
+pub fn handle_set_deposit_program(ctx: Context<SetDepositProgram>) -> Result<()> {
+    let new_deposit_program = &ctx.accounts.new_deposit_program;
+    require!(
+        new_deposit_program.key() == Pubkey::default() || new_deposit_program.executable,
+        ErrorCode::InvalidDepositProgram
+    );
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    let old_deposit_program = distributor.deposit_program;
+    distributor.deposit_program = new_deposit_program.key();
+
+    // Note: might get truncated, do not rely on
+    msg!("set new deposit program to {}", distributor.deposit_program);
+    emit_cpi!(SetDepositProgramEvent {
+        distributor: distributor.key(),
+        old_deposit_program,
+        new_deposit_program: distributor.deposit_program,
+    });
+
+    Ok(())
+}
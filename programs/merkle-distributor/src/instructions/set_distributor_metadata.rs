@@ -0,0 +1,84 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    system_program::System,
+    Accounts, Key, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        admin_event::SetDistributorMetadataEvent, distributor_metadata::DistributorMetadata,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::set_distributor_metadata] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDistributorMetadata<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// The [DistributorMetadata], created on first use.
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"DistributorMetadata".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = DistributorMetadata::LEN,
+        payer = admin
+    )]
+    pub metadata: Account<'info, DistributorMetadata>,
+
+    /// Admin signer, pays for the [DistributorMetadata].
+    #[account(mut, address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the [DistributorMetadata] of a [MerkleDistributor], or replaces all of its fields.
+/// CHECK:
+///     1. The signer is the distributor admin
+///     2. name, campaign_id and uri fit in the [DistributorMetadata]
+#[allow(clippy::result_large_err)]
+pub fn handle_set_distributor_metadata(
+    ctx: Context<SetDistributorMetadata>,
+    name: String,
+    campaign_id: String,
+    uri: String,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        name.len() <= DistributorMetadata::MAX_NAME_LEN
+            && campaign_id.len() <= DistributorMetadata::MAX_CAMPAIGN_ID_LEN
+            && uri.len() <= DistributorMetadata::MAX_URI_LEN,
+        ErrorCode::MetadataTooLong
+    );
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.distributor = ctx.accounts.distributor.key();
+    metadata.bump = *ctx.bumps.get("metadata").unwrap();
+    metadata.name = name;
+    metadata.campaign_id = campaign_id;
+    metadata.uri = uri;
+    metadata.content_hash = content_hash;
+
+    // Note: might get truncated, do not rely on
+    msg!("set distributor metadata {}", metadata.uri);
+    emit_cpi!(SetDistributorMetadataEvent {
+        distributor: metadata.distributor,
+        metadata: metadata.key(),
+        name: metadata.name.clone(),
+        campaign_id: metadata.campaign_id.clone(),
+        uri: metadata.uri.clone(),
+        content_hash: metadata.content_hash,
+    });
+
+    Ok(())
+}
@@ -1,26 +1,34 @@
 use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
 
-use crate::state::merkle_distributor::MerkleDistributor;
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::SetEnableSlotEvent, merkle_distributor::MerkleDistributor},
+};
 
 /// Accounts for [merkle_distributor::set_enable_slot].
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetEnableSlot<'info> {
     /// [MerkleDistributor].
-    #[account(
-        mut,
-        has_one = admin,
-    )]
+    #[account(mut)]
     pub distributor: Account<'info, MerkleDistributor>,
 
-    /// Payer to create the distributor.
-    #[account(mut)]
-    pub admin: Signer<'info>,
+    /// Admin or operator signer
+    #[account(constraint = distributor.is_admin_or_operator(authority.key) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 }
 
 /// set enable slot
 #[allow(clippy::result_large_err)]
 pub fn handle_set_enable_slot(ctx: Context<SetEnableSlot>, enable_slot: u64) -> Result<()> {
     let distributor = &mut ctx.accounts.distributor;
+    let old_enable_slot = distributor.enable_slot;
     distributor.enable_slot = enable_slot;
+
+    emit_cpi!(SetEnableSlotEvent {
+        distributor: distributor.key(),
+        old_enable_slot,
+        new_enable_slot: enable_slot,
+    });
     Ok(())
 }
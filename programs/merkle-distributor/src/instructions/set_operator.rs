@@ -0,0 +1,47 @@
+use anchor_lang::{
+    accounts::{account::Account, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::SetOperatorEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::set_operator] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOperator<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// New operator account
+    /// CHECK: this can be any account, the default pubkey removes the operator
+    pub new_operator: AccountInfo<'info>,
+}
+
+/// Sets the operator, who may set the enable slot and pause but cannot move funds or change roles.
+#[allow(clippy::result_large_err)]
+pub fn handle_set_operator(ctx: Context<SetOperator>) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+
+    let old_operator = distributor.operator;
+    distributor.operator = ctx.accounts.new_operator.key();
+
+    // Note: might get truncated, do not rely on
+    msg!("set new operator to {}", distributor.operator);
+    emit_cpi!(SetOperatorEvent {
+        distributor: distributor.key(),
+        old_operator,
+        new_operator: distributor.operator,
+    });
+
+    Ok(())
+}
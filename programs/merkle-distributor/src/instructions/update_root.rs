@@ -0,0 +1,70 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::RootUpdatedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::update_root] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRoot<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+/// Replaces the root of a [MerkleDistributor] built from a wrong tree, keeping its PDA.
+/// 1. Sets root, max_total_claim and max_num_nodes
+/// 2. Increments root_revision by 1
+/// CHECK:
+///     1. The enable slot is still in the future
+///     2. Nobody has claimed yet
+#[allow(clippy::result_large_err)]
+pub fn handle_update_root(
+    ctx: Context<UpdateRoot>,
+    root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Result<()> {
+    let curr_slot = Clock::get()?.slot;
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(
+        curr_slot < distributor.enable_slot && distributor.num_nodes_claimed == 0,
+        ErrorCode::CannotUpdateRoot
+    );
+
+    let old_root = distributor.root;
+
+    distributor.root = root;
+    distributor.max_total_claim = max_total_claim;
+    distributor.max_num_nodes = max_num_nodes;
+    distributor.root_revision = distributor
+        .root_revision
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Updated root to revision {}, max_total_claim: {}, max_nodes: {}",
+        distributor.root_revision,
+        distributor.max_total_claim,
+        distributor.max_num_nodes,
+    );
+    emit_cpi!(RootUpdatedEvent {
+        distributor: distributor.key(),
+        root_revision: distributor.root_revision,
+        old_root,
+        root,
+        max_total_claim,
+        max_num_nodes,
+    });
+
+    Ok(())
+}
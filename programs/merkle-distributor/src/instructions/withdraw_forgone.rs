@@ -0,0 +1,98 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{admin_event::WithdrawForgoneEvent, merkle_distributor::MerkleDistributor},
+    utils::transfer_from_vault,
+};
+
+/// [merkle_distributor::withdraw_forgone] accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawForgone<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = distributor.mint,
+        token::authority = distributor.key(),
+        token::token_program = token_program,
+        address = distributor.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the forgone tokens to, usually the clawback receiver or a treasury.
+    #[account(mut, token::mint = distributor.mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    #[account(address = distributor.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.mint, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL [Token] or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraws tokens users forwent by claiming early, which no claimant can ever receive.
+/// 1. Transfers amount from the vault to the to account
+/// 2. Increments total_amount_forgone_withdrawn by amount
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. total_amount_forgone_withdrawn stays ≤ total_amount_forgone
+#[allow(clippy::result_large_err)]
+pub fn handle_withdraw_forgone<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawForgone<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let distributor = &ctx.accounts.distributor;
+
+    require!(!distributor.clawed_back, ErrorCode::ClawbackAlreadyClaimed);
+
+    let total_amount_forgone_withdrawn = distributor
+        .total_amount_forgone_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        total_amount_forgone_withdrawn <= distributor.total_amount_forgone,
+        ErrorCode::InsufficientForgoneTokens
+    );
+
+    transfer_from_vault(
+        &ctx.accounts.distributor,
+        &ctx.accounts.from,
+        &ctx.accounts.to,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    distributor.total_amount_forgone_withdrawn = total_amount_forgone_withdrawn;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew {} forgone tokens to {}, {} of {} forgone withdrawn",
+        amount,
+        ctx.accounts.to.key(),
+        distributor.total_amount_forgone_withdrawn,
+        distributor.total_amount_forgone,
+    );
+    emit_cpi!(WithdrawForgoneEvent {
+        distributor: distributor.key(),
+        destination: ctx.accounts.to.key(),
+        amount,
+        total_amount_forgone_withdrawn: distributor.total_amount_forgone_withdrawn,
+    });
+
+    Ok(())
+}
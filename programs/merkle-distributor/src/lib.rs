@@ -15,10 +15,12 @@ extern crate core;
 
 use anchor_lang::prelude::*;
 use instructions::*;
+use state::merkle_distributor::{ClaimMode, ClawbackMode};
 
 pub mod error;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 use solana_security_txt::security_txt;
 
 declare_id!("E7HtfkEMhmn9uwL7EFNydcXBWy5WCYN1vFmKKjipEH1x");
@@ -40,7 +42,8 @@ pub mod merkle_distributor {
 
     /// READ THE FOLLOWING:
     ///
-    /// This instruction is susceptible to frontrunning that could result in loss of funds if not handled properly.
+    /// Distributors created before the admin was part of the distributor seeds were susceptible to frontrunning
+    /// that could result in loss of funds if not handled properly.
     ///
     /// An attack could look like:
     /// - A legitimate user opens a new distributor.
@@ -51,14 +54,17 @@ pub mod merkle_distributor {
     /// and sets the clawback_start_ts with the minimal time allowed. After clawback_start_ts has elapsed,
     /// the attacker can steal all funds from the distributor to their own clawback_receiver account.
     ///
-    /// HOW TO AVOID:
+    /// The distributor address is now derived from the admin signing this instruction, so an attacker replacing
+    /// the admin creates a distributor at another address and cannot occupy the one the legitimate admin funds.
+    ///
+    /// To be extra safe:
     /// - When you call into this instruction, ensure your transaction succeeds.
-    /// - To be extra safe, after your transaction succeeds, read back the state of the created MerkleDistributor account and
+    /// - After your transaction succeeds, read back the state of the created MerkleDistributor account and
     ///   assert the parameters are what you expect, most importantly the clawback_receiver and admin.
     /// - If your transaction fails, double check the value on-chain matches what you expect.
     #[allow(clippy::result_large_err)]
-    pub fn new_distributor(
-        ctx: Context<NewDistributor>,
+    pub fn new_distributor<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewDistributor<'info>>,
         version: u64,
         root: [u8; 32],
         max_total_claim: u64,
@@ -68,6 +74,13 @@ pub mod merkle_distributor {
         clawback_start_ts: i64,
         enable_slot: u64,
         closable: bool,
+        start_claim_pct: u64,
+        claim_mode: ClaimMode,
+        cliff_ts: i64,
+        unlock_period: i64,
+        leaf_vesting: bool,
+        clawback_mode: ClawbackMode,
+        claim_end_ts: i64,
     ) -> Result<()> {
         handle_new_distributor(
             ctx,
@@ -80,11 +93,35 @@ pub mod merkle_distributor {
             clawback_start_ts,
             enable_slot,
             closable,
+            start_claim_pct,
+            claim_mode,
+            cliff_ts,
+            unlock_period,
+            leaf_vesting,
+            clawback_mode,
+            claim_end_ts,
         )
     }
+    /// Creates the program config with the keys allowed to call new_distributor, callable by the upgrade authority
+    #[allow(clippy::result_large_err)]
+    pub fn init_program_config(
+        ctx: Context<InitProgramConfig>,
+        creators: Vec<Pubkey>,
+    ) -> Result<()> {
+        handle_init_program_config(ctx, creators)
+    }
+
+    /// Replaces the keys allowed to call new_distributor, callable by the upgrade authority
+    #[allow(clippy::result_large_err)]
+    pub fn set_creators(ctx: Context<SetCreators>, creators: Vec<Pubkey>) -> Result<()> {
+        handle_set_creators(ctx, creators)
+    }
+
     /// only available in test phase
     #[allow(clippy::result_large_err)]
-    pub fn close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
+    pub fn close_distributor<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseDistributor<'info>>,
+    ) -> Result<()> {
         handle_close_distributor(ctx)
     }
     /// only available in test phase
@@ -93,14 +130,49 @@ pub mod merkle_distributor {
         handle_close_status(ctx)
     }
 
+    /// Lets a claimant reclaim most of the rent of their ClaimStatus once everything is withdrawn
+    #[allow(clippy::result_large_err)]
+    pub fn close_settled_claim_status(ctx: Context<CloseSettledClaimStatus>) -> Result<()> {
+        handle_close_settled_claim_status(ctx)
+    }
+
+    /// Grows a distributor created with an older account layout to the current one, callable by anyone
+    #[allow(clippy::result_large_err)]
+    pub fn migrate_distributor(ctx: Context<MigrateDistributor>) -> Result<()> {
+        handle_migrate_distributor(ctx)
+    }
+
+    /// Callable by the admin or the operator
     #[allow(clippy::result_large_err)]
     pub fn set_enable_slot(ctx: Context<SetEnableSlot>, enable_slot: u64) -> Result<()> {
         handle_set_enable_slot(ctx, enable_slot)
     }
 
+    /// Corrects the root of a distributor nobody could claim from yet
+    #[allow(clippy::result_large_err)]
+    pub fn update_root(
+        ctx: Context<UpdateRoot>,
+        root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    ) -> Result<()> {
+        handle_update_root(ctx, root, max_total_claim, max_num_nodes)
+    }
+
+    /// Stops claims and clawback until unpaused, callable by the admin or the operator
+    #[allow(clippy::result_large_err)]
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        handle_pause(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        handle_unpause(ctx)
+    }
+
     #[allow(clippy::result_large_err)]
-    pub fn new_claim(
-        ctx: Context<NewClaim>,
+    pub fn new_claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
         amount_unlocked: u64,
         amount_locked: u64,
         proof: Vec<[u8; 32]>,
@@ -109,22 +181,163 @@ pub mod merkle_distributor {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
+    pub fn claim_locked<'info>(ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>) -> Result<()> {
         handle_claim_locked(ctx)
     }
 
+    /// Same as new_claim, but the claimant can send the unlocked tokens to any token account of the mint
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_to<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaimTo<'info>>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_to(ctx, amount_unlocked, amount_locked, proof)
+    }
+
+    /// Same as new_claim, then deposits the tokens received into the program allowlisted by the admin,
+    /// with `deposit_data` followed by the amount as instruction data and the remaining accounts as its accounts
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_and_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaimAndDeposit<'info>>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        proof: Vec<[u8; 32]>,
+        deposit_data: Vec<u8>,
+    ) -> Result<()> {
+        handle_new_claim_and_deposit(ctx, amount_unlocked, amount_locked, proof, deposit_data)
+    }
+
+    /// Same as claim_locked, but the claimant can send the tokens to any token account of the mint
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_to<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimLockedTo<'info>>,
+    ) -> Result<()> {
+        handle_claim_locked_to(ctx)
+    }
+
+    /// Same as new_claim, for distributors whose leaves commit to the claimant's own vesting schedule
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_with_vesting<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaim<'info>>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        start_ts: i64,
+        end_ts: i64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_with_vesting(ctx, amount_unlocked, amount_locked, start_ts, end_ts, proof)
+    }
+
+    /// Same as claim_locked, for distributors whose leaves commit to the claimant's own vesting schedule
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_with_vesting<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimLocked<'info>>,
+        start_ts: i64,
+        end_ts: i64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_locked_with_vesting(ctx, start_ts, end_ts, proof)
+    }
+
+    /// Claims from several distributors of the same mint, given a (distributor, claim status, vault) triple
+    /// per claim in the remaining accounts. Creates new claims and withdraws locked tokens of existing ones
+    #[allow(clippy::result_large_err)]
+    pub fn batch_claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchClaim<'info>>,
+        claims: Vec<BatchClaimArgs>,
+    ) -> Result<()> {
+        handle_batch_claim(ctx, claims)
+    }
+
+    /// Creates the [state::claim_bitmap::ClaimBitmap] covering leaves of a bitmap mode distributor
+    #[allow(clippy::result_large_err)]
+    pub fn init_claim_bitmap(ctx: Context<InitClaimBitmap>, bitmap_index: u64) -> Result<()> {
+        handle_init_claim_bitmap(ctx, bitmap_index)
+    }
+
+    /// Claims the unlocked amount of a leaf in a bitmap mode distributor
+    #[allow(clippy::result_large_err)]
+    pub fn new_bitmap_claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewBitmapClaim<'info>>,
+        leaf_index: u64,
+        amount_unlocked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_bitmap_claim(ctx, leaf_index, amount_unlocked, proof)
+    }
+
     #[allow(clippy::result_large_err)]
-    pub fn clawback(ctx: Context<Clawback>) -> Result<()> {
+    pub fn clawback<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
         handle_clawback(ctx)
     }
 
+    /// Closes a clawed back distributor and its vault, returning their rent to the admin
+    #[allow(clippy::result_large_err)]
+    pub fn finalize_distributor<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeDistributor<'info>>,
+    ) -> Result<()> {
+        handle_finalize_distributor(ctx)
+    }
+
+    /// Sends forgone tokens to a token account of the admin's choice ahead of clawback
+    #[allow(clippy::result_large_err)]
+    pub fn withdraw_forgone<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawForgone<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_withdraw_forgone(ctx, amount)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result<()> {
         handle_set_clawback_receiver(ctx)
     }
 
+    /// First step of handing the distributor to a new admin, who must then accept
+    #[allow(clippy::result_large_err)]
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        handle_propose_admin(ctx)
+    }
+
+    /// Signed by the proposed admin to take over the distributor
+    #[allow(clippy::result_large_err)]
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        handle_accept_admin(ctx)
+    }
+
+    /// Allowlists the program new_claim_and_deposit may deposit claimed tokens into
+    #[allow(clippy::result_large_err)]
+    pub fn set_deposit_program(ctx: Context<SetDepositProgram>) -> Result<()> {
+        handle_set_deposit_program(ctx)
+    }
+
+    /// Creates or replaces the metadata describing a distributor
+    #[allow(clippy::result_large_err)]
+    pub fn set_distributor_metadata(
+        ctx: Context<SetDistributorMetadata>,
+        name: String,
+        campaign_id: String,
+        uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        handle_set_distributor_metadata(ctx, name, campaign_id, uri, content_hash)
+    }
+
+    /// Sets the lamport or basis point fee charged on new claims, 0 for none
+    #[allow(clippy::result_large_err)]
+    pub fn set_claim_fee(
+        ctx: Context<SetClaimFee>,
+        claim_fee_lamports: u64,
+        claim_fee_bps: u16,
+    ) -> Result<()> {
+        handle_set_claim_fee(ctx, claim_fee_lamports, claim_fee_bps)
+    }
+
+    /// Delegates setting the enable slot and pausing to an operator key
     #[allow(clippy::result_large_err)]
-    pub fn set_admin(ctx: Context<SetAdmin>) -> Result<()> {
-        handle_set_admin(ctx)
+    pub fn set_operator(ctx: Context<SetOperator>) -> Result<()> {
+        handle_set_operator(ctx)
     }
 }
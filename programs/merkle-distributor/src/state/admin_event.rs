@@ -0,0 +1,215 @@
+use anchor_lang::{event, prelude::*};
+
+/// Emitted when a distributor is created.
+#[event]
+pub struct NewDistributorEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub clawback_receiver: Pubkey,
+    pub root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub clawback_start_ts: i64,
+    pub enable_slot: u64,
+    /// Tokens transferred into the vault by the funder, 0 if funded separately.
+    pub amount_funded: u64,
+    /// New claims are rejected from this timestamp, 0 if accepted until clawback.
+    pub claim_end_ts: i64,
+}
+
+/// Emitted when the vault is clawed back.
+#[event]
+pub struct ClawbackEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Token account the tokens were sent to.
+    pub clawback_receiver: Pubkey,
+    /// Amount of tokens clawed back.
+    pub amount: u64,
+    /// Locked tokens left in the vault for claimants.
+    pub amount_preserved: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin proposes a new admin.
+#[event]
+pub struct AdminProposedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    pub admin: Pubkey,
+    /// Default pubkey if the proposal was cancelled.
+    pub pending_admin: Pubkey,
+}
+
+/// Emitted when the proposed admin accepts and takes over the distributor.
+#[event]
+pub struct AdminAcceptedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when the admin changes the operator.
+#[event]
+pub struct SetOperatorEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    pub old_operator: Pubkey,
+    /// Default pubkey if the operator was removed.
+    pub new_operator: Pubkey,
+}
+
+/// Emitted when the admin allowlists a program for new_claim_and_deposit.
+#[event]
+pub struct SetDepositProgramEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    pub old_deposit_program: Pubkey,
+    /// Default pubkey if deposits were disabled.
+    pub new_deposit_program: Pubkey,
+}
+
+/// Emitted when the admin changes the clawback receiver.
+#[event]
+pub struct SetClawbackReceiverEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    pub old_clawback_receiver: Pubkey,
+    pub new_clawback_receiver: Pubkey,
+}
+
+/// Emitted when the admin changes the enable slot.
+#[event]
+pub struct SetEnableSlotEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    pub old_enable_slot: u64,
+    pub new_enable_slot: u64,
+}
+
+/// Emitted when the admin closes a closable distributor.
+#[event]
+pub struct CloseDistributorEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Token account the vault was emptied into.
+    pub destination: Pubkey,
+    /// Amount of tokens left in the vault.
+    pub amount: u64,
+}
+
+/// Emitted when the admin withdraws forgone tokens.
+#[event]
+pub struct WithdrawForgoneEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Token account the tokens were sent to.
+    pub destination: Pubkey,
+    pub amount: u64,
+    /// Total forgone tokens withdrawn so far, including amount.
+    pub total_amount_forgone_withdrawn: u64,
+}
+
+/// Emitted when the admin corrects the root of a distributor.
+#[event]
+pub struct RootUpdatedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Revision of the new root, starting at 1 for the first correction.
+    pub root_revision: u64,
+    /// Root being replaced.
+    pub old_root: [u8; 32],
+    /// New root.
+    pub root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+}
+
+/// Emitted when the admin pauses a distributor.
+#[event]
+pub struct PausedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin unpauses a distributor.
+#[event]
+pub struct UnpausedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin closes a clawed back distributor and its vault.
+#[event]
+pub struct DistributorFinalizedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Token account the dust left in the vault was sent to.
+    pub clawback_receiver: Pubkey,
+    /// Dust left in the vault.
+    pub amount: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when a distributor is grown to the current account layout.
+#[event]
+pub struct DistributorMigratedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Layout the distributor was migrated to.
+    pub layout_version: u8,
+    /// Lamports the payer added to keep the grown account rent exempt.
+    pub rent_paid: u64,
+}
+
+/// Emitted when the upgrade authority sets who may create distributors.
+#[event]
+pub struct SetCreatorsEvent {
+    /// The [crate::state::program_config::ProgramConfig].
+    pub program_config: Pubkey,
+    /// Keys allowed to create distributors from now on.
+    pub creators: Vec<Pubkey>,
+}
+
+/// Emitted when the admin sets the metadata of a distributor.
+#[event]
+pub struct SetDistributorMetadataEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// The [crate::state::distributor_metadata::DistributorMetadata].
+    pub metadata: Pubkey,
+    /// Display name of the campaign.
+    pub name: String,
+    /// Off-chain identifier of the campaign.
+    pub campaign_id: String,
+    /// URI of the published tree manifest.
+    pub uri: String,
+    /// SHA-256 hash of the published merkle tree.
+    pub content_hash: [u8; 32],
+}
+
+/// Emitted when the admin sets the fee charged on new claims.
+#[event]
+pub struct SetClaimFeeEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Receiver of the claim fee, default if none.
+    pub claim_fee_receiver: Pubkey,
+    /// Lamports charged on each new claim.
+    pub claim_fee_lamports: u64,
+    /// Cut of the unlocked amount of each new claim, in basis points.
+    pub claim_fee_bps: u16,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode::{AlreadyClaimed, InvalidLeafIndex};
+
+/// Bytes of claim bits held by each [ClaimBitmap]
+pub const CLAIM_BITMAP_BYTES: usize = 8192;
+/// Leaves covered by each [ClaimBitmap]
+pub const LEAVES_PER_CLAIM_BITMAP: u64 = 8 * CLAIM_BITMAP_BYTES as u64;
+
+/// Records which leaves of a bitmap mode [crate::state::merkle_distributor::MerkleDistributor]
+/// have been claimed, one bit per leaf.
+/// Leaf `i` lives in the bitmap with index `i / LEAVES_PER_CLAIM_BITMAP`.
+#[account(zero_copy)]
+pub struct ClaimBitmap {
+    /// The distributor this bitmap tracks claims for
+    pub distributor: Pubkey,
+    /// Index of this bitmap, covering leaves [index * LEAVES_PER_CLAIM_BITMAP, (index + 1) * LEAVES_PER_CLAIM_BITMAP)
+    pub index: u64,
+    /// Claim bits, least significant bit first
+    pub bits: [u8; CLAIM_BITMAP_BYTES],
+}
+
+impl ClaimBitmap {
+    pub const LEN: usize = 8 + std::mem::size_of::<ClaimBitmap>();
+
+    /// Returns the bitmap index holding the bit for `leaf_index`
+    pub fn bitmap_index(leaf_index: u64) -> u64 {
+        leaf_index / LEAVES_PER_CLAIM_BITMAP
+    }
+
+    /// Returns the byte offset and mask of `leaf_index` within this bitmap
+    #[allow(clippy::result_large_err)]
+    fn position(&self, leaf_index: u64) -> Result<(usize, u8)> {
+        if Self::bitmap_index(leaf_index) != self.index {
+            return Err(InvalidLeafIndex.into());
+        }
+        let bit = leaf_index % LEAVES_PER_CLAIM_BITMAP;
+        Ok(((bit / 8) as usize, 1 << (bit % 8)))
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn is_claimed(&self, leaf_index: u64) -> Result<bool> {
+        let (byte, mask) = self.position(leaf_index)?;
+        Ok(self.bits[byte] & mask != 0)
+    }
+
+    /// Marks `leaf_index` as claimed, failing if it already was
+    #[allow(clippy::result_large_err)]
+    pub fn set_claimed(&mut self, leaf_index: u64) -> Result<()> {
+        let (byte, mask) = self.position(leaf_index)?;
+        if self.bits[byte] & mask != 0 {
+            return Err(AlreadyClaimed.into());
+        }
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::ErrorCode::{AlreadyClaimed, InvalidLeafIndex};
+    use crate::state::claim_bitmap::{ClaimBitmap, CLAIM_BITMAP_BYTES, LEAVES_PER_CLAIM_BITMAP};
+    use anchor_lang::prelude::Pubkey;
+
+    fn new_bitmap(index: u64) -> ClaimBitmap {
+        ClaimBitmap {
+            distributor: Pubkey::default(),
+            index,
+            bits: [0; CLAIM_BITMAP_BYTES],
+        }
+    }
+
+    #[test]
+    fn set_claimed() {
+        let mut bitmap = new_bitmap(1);
+        let leaf_index = LEAVES_PER_CLAIM_BITMAP + 9;
+
+        assert_eq!(bitmap.is_claimed(leaf_index), Ok(false));
+        bitmap.set_claimed(leaf_index).unwrap();
+        assert_eq!(bitmap.is_claimed(leaf_index), Ok(true));
+        assert_eq!(bitmap.bits[1], 0b10);
+
+        // neighbouring leaves are untouched
+        assert_eq!(bitmap.is_claimed(leaf_index - 1), Ok(false));
+        assert_eq!(bitmap.is_claimed(leaf_index + 1), Ok(false));
+
+        assert_eq!(bitmap.set_claimed(leaf_index), Err(AlreadyClaimed.into()));
+    }
+
+    #[test]
+    fn leaf_outside_bitmap() {
+        let mut bitmap = new_bitmap(1);
+
+        assert_eq!(
+            bitmap.is_claimed(LEAVES_PER_CLAIM_BITMAP - 1),
+            Err(InvalidLeafIndex.into())
+        );
+        assert_eq!(
+            bitmap.set_claimed(2 * LEAVES_PER_CLAIM_BITMAP),
+            Err(InvalidLeafIndex.into())
+        );
+        assert_eq!(ClaimBitmap::bitmap_index(2 * LEAVES_PER_CLAIM_BITMAP), 2);
+    }
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode::{ArithmeticError, ClaimingIsNotStarted};
 
+/// Start claim pct of distributors created before `MerkleDistributor::start_claim_pct` existed
 pub const START_CLAIM_PCT: u128 = 500_000; // .5
 pub const PCT_PRECISION: u128 = 1_000_000;
 
@@ -23,6 +24,9 @@ pub struct ClaimStatus {
     pub closable: bool, // 1
     /// admin of merkle tree, store for for testing purpose
     pub distributor: Pubkey,
+    /// Whether the rent was paid by a sponsor rather than the claimant, who then cannot reclaim it.
+    /// Fits in the padding of [ClaimStatus::LEN], so claim statuses created before it read as false.
+    pub rent_sponsored: bool,
 }
 
 impl PartialEq for ClaimStatus {
@@ -34,18 +38,48 @@ impl PartialEq for ClaimStatus {
             && self.unlocked_amount_claimed == other.unlocked_amount_claimed
             && self.closable == other.closable
             && self.distributor == other.distributor
+            && self.rent_sponsored == other.rent_sponsored
     }
 }
 
 impl ClaimStatus {
     pub const LEN: usize = 8 + std::mem::size_of::<ClaimStatus>();
+    /// Offset of `distributor` in the account data, which readers filter on.
+    /// Fields must only ever be appended after it so this holds for every layout.
+    pub const DISTRIBUTOR_OFFSET: usize = 8 + 32 + 8 * 4 + 1;
+    /// Size a settled ClaimStatus is shrunk to by close_settled_claim_status, only its discriminator.
+    pub const TOMBSTONE_LEN: usize = 8;
+
+    /// Deserializes a claim status of any layout up to the current one, zero-filling fields appended since.
+    /// Tombstones left by close_settled_claim_status are too short to hold a claimant and fail.
+    pub fn try_deserialize_any_layout(data: &[u8]) -> Result<Self> {
+        if data.len() >= Self::LEN || data.len() <= Self::DISTRIBUTOR_OFFSET {
+            return Self::try_deserialize(&mut &data[..]);
+        }
+
+        let mut padded = vec![0u8; Self::LEN];
+        padded[..data.len()].copy_from_slice(data);
+        Self::try_deserialize(&mut padded.as_slice())
+    }
+
+    /// Whether every locked token was withdrawn, leaving nothing owed to the claimant.
+    pub fn is_settled(&self) -> bool {
+        self.locked_amount_withdrawn == self.locked_amount
+    }
 
     /// Returns amount withdrawable, factoring in unlocked tokens and previous withdraws.
     /// payout is difference between the amount unlocked and the amount withdrawn
     #[allow(clippy::result_large_err)]
-    pub fn amount_withdrawable(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
+    pub fn amount_withdrawable(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        cliff_ts: i64,
+        unlock_period: i64,
+    ) -> Result<u64> {
         let amount = self
-            .unlocked_amount(curr_ts, start_ts, end_ts)?
+            .unlocked_amount(curr_ts, start_ts, end_ts, cliff_ts, unlock_period)?
             .checked_sub(self.locked_amount_withdrawn)
             .ok_or(ArithmeticError)?;
 
@@ -54,6 +88,8 @@ impl ClaimStatus {
 
     /// Total amount unlocked
     /// Equal to (time_into_unlock / total_unlock_time) * locked_amount
+    /// Nothing unlocks before cliff_ts, and if unlock_period is set time_into_unlock is rounded
+    /// down to a multiple of it so the amount unlocks in steps. All of it is unlocked at end_ts.
     /// Multiplication safety:
     ///    The maximum possible product is (2^64 -1) * (2^64 -1) = 2^128 - 2^65 + 1
     ///    which is less than 2^128 - 1 (the maximum value of a u128), meaning that
@@ -68,12 +104,24 @@ impl ClaimStatus {
     ///         b < 2^64 -1.
     ///     Since b is a i64, this is always true, so no truncation can occur
     #[allow(clippy::result_large_err)]
-    pub fn unlocked_amount(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
-        if curr_ts >= start_ts {
+    pub fn unlocked_amount(
+        &self,
+        curr_ts: i64,
+        start_ts: i64,
+        end_ts: i64,
+        cliff_ts: i64,
+        unlock_period: i64,
+    ) -> Result<u64> {
+        if curr_ts >= start_ts && curr_ts >= cliff_ts {
             if curr_ts >= end_ts {
                 Ok(self.locked_amount)
             } else {
-                let time_into_unlock = curr_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+                let mut time_into_unlock = curr_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
+                if unlock_period > 0 {
+                    time_into_unlock = time_into_unlock
+                        .checked_sub(time_into_unlock % unlock_period)
+                        .ok_or(ArithmeticError)?;
+                }
                 let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
 
                 let amount = ((time_into_unlock as u128)
@@ -95,6 +143,7 @@ impl ClaimStatus {
         curr_ts: i64,
         start_ts: i64,
         end_ts: i64,
+        start_claim_pct: u128,
     ) -> Result<()> {
         if curr_ts >= start_ts {
             if curr_ts >= end_ts {
@@ -104,7 +153,7 @@ impl ClaimStatus {
                 let total_unlock_time = end_ts.checked_sub(start_ts).ok_or(ArithmeticError)?;
 
                 let start_amount = (self.unlocked_amount as u128)
-                    .checked_mul(START_CLAIM_PCT)
+                    .checked_mul(start_claim_pct)
                     .ok_or(ArithmeticError)?
                     .checked_div(PCT_PRECISION)
                     .ok_or(ArithmeticError)? as u64;
@@ -140,8 +189,13 @@ impl ClaimStatus {
 
 #[cfg(test)]
 mod test {
+    use anchor_lang::{prelude::Pubkey, AccountDeserialize, AccountSerialize, Discriminator};
+
     use crate::error::ErrorCode::ClaimingIsNotStarted;
-    use crate::state::claim_status::ClaimStatus;
+    use crate::state::{
+        claim_status::{ClaimStatus, PCT_PRECISION, START_CLAIM_PCT},
+        merkle_distributor::MerkleDistributor,
+    };
 
     #[test]
     fn update_unlocked_amount_claimed() {
@@ -154,7 +208,12 @@ mod test {
         let start_ts = 1;
         let end_ts = 10;
 
-        let result = claim_status.update_unlocked_amount_claimed(current_ts, start_ts, end_ts);
+        let result = claim_status.update_unlocked_amount_claimed(
+            current_ts,
+            start_ts,
+            end_ts,
+            START_CLAIM_PCT,
+        );
 
         assert_eq!(result, Err(ClaimingIsNotStarted.into()));
 
@@ -168,7 +227,7 @@ mod test {
         let end_ts = 11;
 
         let result = claim_status
-            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts)
+            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts, START_CLAIM_PCT)
             .unwrap();
 
         assert_eq!(claim_status.unlocked_amount_claimed, 500_000);
@@ -179,7 +238,7 @@ mod test {
         let end_ts = 11;
 
         let result = claim_status
-            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts)
+            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts, START_CLAIM_PCT)
             .unwrap();
 
         assert_eq!(claim_status.unlocked_amount_claimed, 750_000);
@@ -190,7 +249,7 @@ mod test {
         let end_ts = 11;
 
         let result = claim_status
-            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts)
+            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts, START_CLAIM_PCT)
             .unwrap();
 
         assert_eq!(claim_status.unlocked_amount_claimed, 1_000_000);
@@ -201,10 +260,388 @@ mod test {
         let end_ts = 11;
 
         let result = claim_status
-            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts)
+            .update_unlocked_amount_claimed(current_ts, start_ts, end_ts, START_CLAIM_PCT)
             .unwrap();
 
         assert_eq!(claim_status.unlocked_amount_claimed, 1_000_000);
         assert_eq!(claim_status.get_unlocked_amount_forgone(), Ok(0));
     }
+
+    #[test]
+    fn update_unlocked_amount_claimed_with_start_claim_pct() {
+        let mut claim_status = ClaimStatus {
+            unlocked_amount: 1_000_000,
+            ..ClaimStatus::default()
+        };
+
+        let start_ts = 1;
+        let end_ts = 11;
+
+        // 20% up front, remaining 80% unlocks linearly
+        claim_status
+            .update_unlocked_amount_claimed(1, start_ts, end_ts, 200_000)
+            .unwrap();
+        assert_eq!(claim_status.unlocked_amount_claimed, 200_000);
+        assert_eq!(claim_status.get_unlocked_amount_forgone(), Ok(800_000));
+
+        claim_status
+            .update_unlocked_amount_claimed(6, start_ts, end_ts, 200_000)
+            .unwrap();
+        assert_eq!(claim_status.unlocked_amount_claimed, 600_000);
+        assert_eq!(claim_status.get_unlocked_amount_forgone(), Ok(400_000));
+
+        // 100% up front, nothing is forgone
+        claim_status
+            .update_unlocked_amount_claimed(1, start_ts, end_ts, PCT_PRECISION)
+            .unwrap();
+        assert_eq!(claim_status.unlocked_amount_claimed, 1_000_000);
+        assert_eq!(claim_status.get_unlocked_amount_forgone(), Ok(0));
+    }
+
+    #[test]
+    fn unlocked_amount() {
+        let claim_status = ClaimStatus {
+            locked_amount: 1_000_000,
+            ..ClaimStatus::default()
+        };
+
+        let start_ts = 1;
+        let end_ts = 11;
+
+        assert_eq!(
+            claim_status.unlocked_amount(0, start_ts, end_ts, 0, 0),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(1, start_ts, end_ts, 0, 0),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(6, start_ts, end_ts, 0, 0),
+            Ok(500_000)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(11, start_ts, end_ts, 0, 0),
+            Ok(1_000_000)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(12, start_ts, end_ts, 0, 0),
+            Ok(1_000_000)
+        );
+    }
+
+    #[test]
+    fn unlocked_amount_with_cliff() {
+        let claim_status = ClaimStatus {
+            locked_amount: 1_000_000,
+            ..ClaimStatus::default()
+        };
+
+        let start_ts = 1;
+        let end_ts = 11;
+        let cliff_ts = 5;
+
+        // nothing before the cliff
+        assert_eq!(
+            claim_status.unlocked_amount(4, start_ts, end_ts, cliff_ts, 0),
+            Ok(0)
+        );
+        // everything accrued since start_ts unlocks at the cliff
+        assert_eq!(
+            claim_status.unlocked_amount(5, start_ts, end_ts, cliff_ts, 0),
+            Ok(400_000)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(6, start_ts, end_ts, cliff_ts, 0),
+            Ok(500_000)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(11, start_ts, end_ts, cliff_ts, 0),
+            Ok(1_000_000)
+        );
+    }
+
+    #[test]
+    fn unlocked_amount_with_unlock_period() {
+        let mut claim_status = ClaimStatus {
+            locked_amount: 1_000_000,
+            ..ClaimStatus::default()
+        };
+
+        let start_ts = 1;
+        let end_ts = 11;
+        let unlock_period = 3;
+
+        assert_eq!(
+            claim_status.unlocked_amount(3, start_ts, end_ts, 0, unlock_period),
+            Ok(0)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(4, start_ts, end_ts, 0, unlock_period),
+            Ok(300_000)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(6, start_ts, end_ts, 0, unlock_period),
+            Ok(300_000)
+        );
+        assert_eq!(
+            claim_status.unlocked_amount(10, start_ts, end_ts, 0, unlock_period),
+            Ok(900_000)
+        );
+        // the last partial step unlocks at end_ts
+        assert_eq!(
+            claim_status.unlocked_amount(11, start_ts, end_ts, 0, unlock_period),
+            Ok(1_000_000)
+        );
+
+        // combined with a cliff, only what has not been withdrawn yet is withdrawable
+        claim_status.locked_amount_withdrawn = 300_000;
+        assert_eq!(
+            claim_status.amount_withdrawable(7, start_ts, end_ts, 6, unlock_period),
+            Ok(300_000)
+        );
+    }
+
+    #[test]
+    fn get_start_claim_pct() {
+        let distributor = MerkleDistributor::default();
+        assert_eq!(distributor.get_start_claim_pct(), START_CLAIM_PCT);
+
+        let distributor = MerkleDistributor {
+            start_claim_pct: 250_000,
+            start_claim_pct_set: true,
+            ..MerkleDistributor::default()
+        };
+        assert_eq!(distributor.get_start_claim_pct(), 250_000);
+
+        // nothing claimable at start_ts is allowed, unlike legacy distributors
+        let distributor = MerkleDistributor {
+            start_claim_pct: 0,
+            start_claim_pct_set: true,
+            ..MerkleDistributor::default()
+        };
+        assert_eq!(distributor.get_start_claim_pct(), 0);
+    }
+
+    #[test]
+    fn is_admin_or_operator() {
+        let admin = Pubkey::new_unique();
+        let distributor = MerkleDistributor {
+            admin,
+            ..MerkleDistributor::default()
+        };
+        assert!(distributor.is_admin_or_operator(&admin));
+        // an unset operator must not let the default pubkey through
+        assert!(!distributor.is_admin_or_operator(&Pubkey::default()));
+        assert!(!distributor.is_admin_or_operator(&Pubkey::new_unique()));
+
+        let operator = Pubkey::new_unique();
+        let distributor = MerkleDistributor {
+            admin,
+            operator,
+            ..MerkleDistributor::default()
+        };
+        assert!(distributor.is_admin_or_operator(&admin));
+        assert!(distributor.is_admin_or_operator(&operator));
+        assert!(!distributor.is_admin_or_operator(&Pubkey::default()));
+    }
+
+    #[test]
+    fn accepts_new_claims() {
+        // 0 means new claims are accepted until clawback
+        let distributor = MerkleDistributor::default();
+        assert!(distributor.accepts_new_claims(0));
+        assert!(distributor.accepts_new_claims(i64::MAX));
+
+        let distributor = MerkleDistributor {
+            claim_end_ts: 100,
+            ..MerkleDistributor::default()
+        };
+        assert!(distributor.accepts_new_claims(99));
+        assert!(!distributor.accepts_new_claims(100));
+        assert!(!distributor.accepts_new_claims(101));
+    }
+
+    #[test]
+    fn has_claim_fee() {
+        assert!(!MerkleDistributor::default().has_claim_fee());
+
+        let distributor = MerkleDistributor {
+            claim_fee_lamports: 5_000,
+            ..MerkleDistributor::default()
+        };
+        assert!(distributor.has_claim_fee());
+
+        let distributor = MerkleDistributor {
+            claim_fee_bps: 1,
+            ..MerkleDistributor::default()
+        };
+        assert!(distributor.has_claim_fee());
+
+        // a receiver alone does not charge anything
+        let distributor = MerkleDistributor {
+            claim_fee_receiver: Pubkey::new_unique(),
+            ..MerkleDistributor::default()
+        };
+        assert!(!distributor.has_claim_fee());
+    }
+
+    #[test]
+    fn get_claim_fee_amount() {
+        let distributor = MerkleDistributor::default();
+        assert_eq!(distributor.get_claim_fee_amount(1_000_000), 0);
+
+        let distributor = MerkleDistributor {
+            claim_fee_bps: 250,
+            ..MerkleDistributor::default()
+        };
+        assert_eq!(distributor.get_claim_fee_amount(1_000_000), 25_000);
+        // rounded down in favour of the claimant
+        assert_eq!(distributor.get_claim_fee_amount(39), 0);
+        assert_eq!(distributor.get_claim_fee_amount(40), 1);
+        assert_eq!(distributor.get_claim_fee_amount(79), 1);
+
+        // no overflow at the maximum fee and amount
+        let distributor = MerkleDistributor {
+            claim_fee_bps: MerkleDistributor::MAX_CLAIM_FEE_BPS,
+            ..MerkleDistributor::default()
+        };
+        assert_eq!(distributor.get_claim_fee_amount(u64::MAX), u64::MAX / 10);
+    }
+
+    /// Account data of `distributor` as stored on chain, `len` bytes long.
+    fn distributor_data(distributor: &MerkleDistributor, len: usize) -> Vec<u8> {
+        let mut data = vec![];
+        distributor.try_serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    #[test]
+    fn distributor_any_layout_v1() {
+        let mint = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+        let distributor = MerkleDistributor {
+            version: 3,
+            mint,
+            max_total_claim: 1_000_000,
+            admin,
+            // appended since v1, stripped by the truncation below
+            base: Pubkey::new_unique(),
+            claim_end_ts: 1_700_000_000,
+            claim_fee_lamports: 5_000,
+            ..MerkleDistributor::default()
+        };
+
+        let distributor = MerkleDistributor::try_deserialize_any_layout(&distributor_data(
+            &distributor,
+            MerkleDistributor::LEN_V1,
+        ))
+        .unwrap();
+        assert_eq!(distributor.version, 3);
+        assert_eq!(distributor.mint, mint);
+        assert_eq!(distributor.max_total_claim, 1_000_000);
+        assert_eq!(distributor.admin, admin);
+        assert_eq!(distributor.layout_version, 0);
+        assert_eq!(distributor.base, Pubkey::default());
+        assert_eq!(distributor.claim_end_ts, 0);
+        assert_eq!(distributor.claim_fee_lamports, 0);
+    }
+
+    #[test]
+    fn distributor_any_layout_v2() {
+        let operator = Pubkey::new_unique();
+        let distributor = MerkleDistributor {
+            version: 3,
+            layout_version: 2,
+            operator,
+            claim_end_ts: 1_700_000_000,
+            // appended since v2, stripped by the truncation below
+            claim_fee_bps: 50,
+            total_claim_fee_lamports: 5_000,
+            ..MerkleDistributor::default()
+        };
+
+        let distributor = MerkleDistributor::try_deserialize_any_layout(&distributor_data(
+            &distributor,
+            MerkleDistributor::LEN_V2,
+        ))
+        .unwrap();
+        assert_eq!(distributor.layout_version, 2);
+        assert_eq!(distributor.operator, operator);
+        assert_eq!(distributor.claim_end_ts, 1_700_000_000);
+        assert_eq!(distributor.claim_fee_bps, 0);
+        assert_eq!(distributor.total_claim_fee_lamports, 0);
+    }
+
+    #[test]
+    fn distributor_any_layout_current() {
+        let operator = Pubkey::new_unique();
+        let claim_fee_receiver = Pubkey::new_unique();
+        let distributor = MerkleDistributor {
+            version: 3,
+            layout_version: MerkleDistributor::LAYOUT_VERSION,
+            operator,
+            claim_end_ts: 1_700_000_000,
+            claim_fee_receiver,
+            total_claim_fee_amount: 42,
+            ..MerkleDistributor::default()
+        };
+
+        let distributor = MerkleDistributor::try_deserialize_any_layout(&distributor_data(
+            &distributor,
+            MerkleDistributor::LEN,
+        ))
+        .unwrap();
+        assert_eq!(distributor.version, 3);
+        assert_eq!(
+            distributor.layout_version,
+            MerkleDistributor::LAYOUT_VERSION
+        );
+        assert_eq!(distributor.operator, operator);
+        assert_eq!(distributor.claim_end_ts, 1_700_000_000);
+        assert_eq!(distributor.claim_fee_receiver, claim_fee_receiver);
+        assert_eq!(distributor.total_claim_fee_amount, 42);
+    }
+
+    #[test]
+    fn claim_status_any_layout_tombstone() {
+        let claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            locked_amount: 100,
+            locked_amount_withdrawn: 100,
+            distributor: Pubkey::new_unique(),
+            ..ClaimStatus::default()
+        };
+        let mut data = vec![];
+        claim_status.try_serialize(&mut data).unwrap();
+        assert!(ClaimStatus::try_deserialize_any_layout(&data).unwrap() == claim_status);
+
+        // close_settled_claim_status leaves only the discriminator
+        let tombstone = ClaimStatus::DISCRIMINATOR.to_vec();
+        assert_eq!(tombstone.len(), ClaimStatus::TOMBSTONE_LEN);
+        assert!(ClaimStatus::try_deserialize_any_layout(&tombstone).is_err());
+    }
+
+    #[test]
+    fn claim_status_rent_sponsored_in_padding() {
+        // same size as before rent_sponsored, so existing claim statuses still load and save
+        assert_eq!(ClaimStatus::LEN, 112);
+
+        let claim_status = ClaimStatus {
+            claimant: Pubkey::new_unique(),
+            distributor: Pubkey::new_unique(),
+            rent_sponsored: true,
+            ..ClaimStatus::default()
+        };
+        let mut data = vec![];
+        claim_status.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= ClaimStatus::LEN);
+
+        // written before rent_sponsored existed, its byte is zeroed padding
+        data.truncate(ClaimStatus::DISTRIBUTOR_OFFSET + 32);
+        data.resize(ClaimStatus::LEN, 0);
+        let claim_status = ClaimStatus::try_deserialize(&mut data.as_slice()).unwrap();
+        assert!(!claim_status.rent_sponsored);
+    }
 }
@@ -3,19 +3,52 @@ use anchor_lang::{event, prelude::*};
 /// Emitted when a new claim is created.
 #[event]
 pub struct NewClaimEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
     /// User that claimed.
     pub claimant: Pubkey,
+    /// Token account the claimed tokens were sent to.
+    pub destination: Pubkey,
     /// Timestamp.
     pub timestamp: i64,
     pub amount_claimed: u64,
     pub amount_forgone: u64,
+    /// Transfer fee withheld by a Token-2022 mint, included in amount_claimed.
+    pub transfer_fee: u64,
+    /// Program the claimed tokens were deposited into by new_claim_and_deposit, default otherwise.
+    pub deposit_program: Pubkey,
+    /// Tokens cut from the unlocked amount for the claim fee receiver, on top of amount_claimed.
+    pub claim_fee: u64,
+    /// Lamports the payer sent to the claim fee receiver.
+    pub claim_fee_lamports: u64,
 }
 
 /// Emitted when tokens are claimed.
 #[event]
 pub struct ClaimedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
     /// User that claimed.
     pub claimant: Pubkey,
+    /// Token account the claimed tokens were sent to.
+    pub destination: Pubkey,
     /// Amount of tokens to distribute.
     pub amount: u64,
+    /// Transfer fee withheld by a Token-2022 mint, included in amount.
+    pub transfer_fee: u64,
+}
+
+/// Emitted when a claimant closes their settled ClaimStatus.
+#[event]
+pub struct ClaimStatusClosedEvent {
+    /// The [crate::state::merkle_distributor::MerkleDistributor].
+    pub distributor: Pubkey,
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Lamports returned to the claimant, the rest keeps the tombstone rent exempt.
+    pub rent_reclaimed: u64,
 }
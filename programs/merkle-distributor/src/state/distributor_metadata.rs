@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Optional description of a [crate::state::merkle_distributor::MerkleDistributor] for explorers and frontends,
+/// set by its admin.
+#[account]
+#[derive(Default, Debug)]
+pub struct DistributorMetadata {
+    /// The distributor this metadata describes.
+    pub distributor: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// Display name of the campaign, at most [DistributorMetadata::MAX_NAME_LEN] bytes
+    pub name: String,
+    /// Off-chain identifier of the campaign, at most [DistributorMetadata::MAX_CAMPAIGN_ID_LEN] bytes
+    pub campaign_id: String,
+    /// URI of the published tree manifest, at most [DistributorMetadata::MAX_URI_LEN] bytes
+    pub uri: String,
+    /// SHA-256 hash of the published merkle tree of the distributor
+    pub content_hash: [u8; 32],
+}
+
+impl DistributorMetadata {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_CAMPAIGN_ID_LEN: usize = 64;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const LEN: usize = 8
+        + 32
+        + 1
+        + 4
+        + Self::MAX_NAME_LEN
+        + 4
+        + Self::MAX_CAMPAIGN_ID_LEN
+        + 4
+        + Self::MAX_URI_LEN
+        + 32;
+}
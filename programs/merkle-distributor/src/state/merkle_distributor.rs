@@ -3,6 +3,31 @@ use anchor_lang::{
     prelude::{Pubkey, *},
 };
 
+use crate::state::claim_status::START_CLAIM_PCT;
+
+/// Basis points in a whole, the precision of claim_fee_bps.
+pub const BPS_PRECISION: u128 = 10_000;
+
+/// How a [MerkleDistributor] records that a leaf has been claimed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClaimMode {
+    /// A [crate::state::claim_status::ClaimStatus] PDA per claimant, supports locked amounts
+    #[default]
+    ClaimStatus,
+    /// A bit per leaf in [crate::state::claim_bitmap::ClaimBitmap] accounts, unlocked amounts only
+    Bitmap,
+}
+
+/// What a [MerkleDistributor] clawback leaves in the vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClawbackMode {
+    /// The whole vault is clawed back, locked amounts not yet withdrawn are lost
+    #[default]
+    Full,
+    /// Locked amounts owed to existing claimants stay in the vault and can still be withdrawn
+    PreserveLocked,
+}
+
 /// State for the account which distributes tokens.
 #[account]
 #[derive(Default, Debug)]
@@ -43,14 +68,117 @@ pub struct MerkleDistributor {
     pub enable_slot: u64,
     /// indicate that whether admin can close this pool, for testing purpose
     pub closable: bool,
+    /// Share of the unlocked amount claimable at start_ts, scaled by PCT_PRECISION.
+    /// Only set if start_claim_pct_set, see [MerkleDistributor::get_start_claim_pct]
+    pub start_claim_pct: u64,
+    /// How claims are recorded, [ClaimMode::ClaimStatus] for legacy distributors
+    pub claim_mode: ClaimMode,
+    /// Whether each leaf commits to its own vesting start and end, overriding start_ts and end_ts
+    pub leaf_vesting: bool,
+    /// What clawback leaves in the vault, [ClawbackMode::Full] for legacy distributors
+    pub clawback_mode: ClawbackMode,
+    /// Whether the admin paused claims and clawback
+    pub paused: bool,
+    /// Number of times the root was corrected with update_root, 0 for the original root
+    pub root_revision: u64,
+    /// Layout of the account, [MerkleDistributor::LAYOUT_VERSION] once created or migrated.
+    /// 0 for distributors created before layouts were versioned, which are [MerkleDistributor::LEN_V1] bytes,
+    /// 2 for those created before the claim fee, which are [MerkleDistributor::LEN_V2] bytes
+    pub layout_version: u8,
     /// Buffer 0
-    pub buffer_0: [u8; 32],
-    /// Buffer 1
-    pub buffer_1: [u8; 32],
-    /// Buffer 2
-    pub buffer_2: [u8; 32],
+    pub buffer_0: [u8; 10],
+    /// Whether new_distributor set start_claim_pct, false for distributors created before it was configurable
+    pub start_claim_pct_set: bool,
+    /// Locked amounts don't unlock before this timestamp, 0 for no cliff
+    pub cliff_ts: i64,
+    /// Locked amounts unlock in steps of this many seconds from start_ts, 0 for a linear unlock
+    pub unlock_period: i64,
+    /// Total amount of forgone tokens the admin has withdrawn from the vault.
+    pub total_amount_forgone_withdrawn: u64,
+    /// Sum of locked_amount - locked_amount_withdrawn over all ClaimStatus accounts, reserved by
+    /// [ClawbackMode::PreserveLocked]. Misses claims made before it was tracked, so only new distributors use that mode
+    pub total_locked_outstanding: u64,
+    /// Admin proposed by the current admin, who becomes admin once they accept. Default if none
+    pub pending_admin: Pubkey,
+    /// Key allowed to set the enable slot and pause alongside the admin, without any power over funds.
+    /// Default if none
+    pub operator: Pubkey,
+    /// Number of ClaimStatus accounts closed by their claimant once fully withdrawn
+    pub num_nodes_settled: u64,
+    /// Admin that created the distributor, part of its PDA seeds.
+    /// Default for distributors created before, whose seeds are only the mint and version
+    pub base: Pubkey,
+    /// New claims are rejected from this timestamp while existing claimants keep withdrawing locked amounts,
+    /// 0 to accept them until clawback
+    pub claim_end_ts: i64,
+    /// Program new_claim_and_deposit may send claimed tokens to, allowlisted by the admin. Default if none
+    pub deposit_program: Pubkey,
+    /// Receives the claim fee: a wallet for claim_fee_lamports, a token account of the mint for claim_fee_bps.
+    /// Default if none
+    pub claim_fee_receiver: Pubkey,
+    /// Lamports the payer of each new claim sends to claim_fee_receiver. 0 if none
+    pub claim_fee_lamports: u64,
+    /// Total tokens cut from new claims for claim_fee_receiver, which left the vault but are not part of
+    /// total_amount_claimed
+    pub total_claim_fee_amount: u64,
+    /// Cut of the unlocked amount of each new claim sent to claim_fee_receiver, in basis points. 0 if none
+    pub claim_fee_bps: u16,
+    /// Total lamports new claims paid to claim_fee_receiver, not part of any token total.
+    pub total_claim_fee_lamports: u64,
 }
 
 impl MerkleDistributor {
     pub const LEN: usize = 8 + std::mem::size_of::<MerkleDistributor>();
+    /// Size of distributors created before the operator was added, which migrate_distributor grows to [MerkleDistributor::LEN].
+    pub const LEN_V1: usize = 352;
+    /// Size of distributors created before the claim fee was added, at layout version 2.
+    pub const LEN_V2: usize = 512;
+    /// Layout of accounts [MerkleDistributor::LEN] bytes long.
+    pub const LAYOUT_VERSION: u8 = 3;
+    /// Highest claim_fee_bps the admin can set.
+    pub const MAX_CLAIM_FEE_BPS: u16 = 1_000;
+
+    /// Deserializes a distributor in any layout.
+    /// Fields appended since are zero in an older account, which every field treats as its legacy default.
+    /// Data shorter than any layout fails.
+    pub fn try_deserialize_any_layout(data: &[u8]) -> Result<Self> {
+        if data.len() >= Self::LEN || data.len() < Self::LEN_V1 {
+            return Self::try_deserialize(&mut &data[..]);
+        }
+
+        let mut padded = vec![0u8; Self::LEN];
+        padded[..data.len()].copy_from_slice(data);
+        Self::try_deserialize(&mut padded.as_slice())
+    }
+
+    /// Whether `key` is the admin or the operator, who may both set the enable slot and pause.
+    pub fn is_admin_or_operator(&self, key: &Pubkey) -> bool {
+        *key == self.admin || (*key == self.operator && self.operator != Pubkey::default())
+    }
+
+    /// Whether new claims are still accepted at `curr_ts`, before claim_end_ts if set.
+    pub fn accepts_new_claims(&self, curr_ts: i64) -> bool {
+        self.claim_end_ts == 0 || curr_ts < self.claim_end_ts
+    }
+
+    /// Whether new claims pay a fee to claim_fee_receiver, either in lamports or as a cut of the unlocked amount.
+    pub fn has_claim_fee(&self) -> bool {
+        self.claim_fee_lamports > 0 || self.claim_fee_bps > 0
+    }
+
+    /// Cut of `unlocked_amount_claimed` sent to claim_fee_receiver, rounded down in favour of the claimant.
+    pub fn get_claim_fee_amount(&self, unlocked_amount_claimed: u64) -> u64 {
+        // at most unlocked_amount_claimed as claim_fee_bps ≤ MAX_CLAIM_FEE_BPS < BPS_PRECISION
+        (unlocked_amount_claimed as u128 * self.claim_fee_bps as u128 / BPS_PRECISION) as u64
+    }
+
+    /// Share of the unlocked amount claimable at start_ts, scaled by PCT_PRECISION.
+    /// Distributors created before start_claim_pct existed fall back to START_CLAIM_PCT.
+    pub fn get_start_claim_pct(&self) -> u128 {
+        if self.start_claim_pct_set {
+            self.start_claim_pct as u128
+        } else {
+            START_CLAIM_PCT
+        }
+    }
 }
@@ -1,3 +1,7 @@
+pub mod admin_event;
+pub mod claim_bitmap;
 pub mod claim_status;
 pub mod claimed_event;
+pub mod distributor_metadata;
 pub mod merkle_distributor;
+pub mod program_config;
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Singleton holding the program-wide settings, managed by the program upgrade authority.
+#[account]
+#[derive(Default, Debug)]
+pub struct ProgramConfig {
+    /// Bump seed.
+    pub bump: u8,
+    /// Keys allowed to create distributors, at most [ProgramConfig::MAX_CREATORS]
+    pub creators: Vec<Pubkey>,
+}
+
+impl ProgramConfig {
+    pub const MAX_CREATORS: usize = 16;
+    pub const LEN: usize = 8 + 1 + 4 + 32 * Self::MAX_CREATORS;
+
+    /// Whether `key` may create distributors.
+    pub fn is_creator(&self, key: &Pubkey) -> bool {
+        self.creators.contains(key)
+    }
+}
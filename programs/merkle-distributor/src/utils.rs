@@ -0,0 +1,254 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Allocate, Assign, CreateAccount, Transfer},
+};
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    onchain::invoke_transfer_checked,
+};
+
+use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+
+/// Transfers `amount` out of the distributor vault with `transfer_checked`, signing as the
+/// [MerkleDistributor] PDA.
+/// Returns the transfer fee withheld from the recipient, which is 0 for SPL Token mints.
+#[allow(clippy::result_large_err)]
+pub fn transfer_from_vault<'info>(
+    distributor: &Account<'info, MerkleDistributor>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let transfer_fee = get_transfer_fee(&mint.to_account_info(), amount)?;
+
+    with_distributor_signer(distributor, |signer_seeds| {
+        invoke_transfer_checked(
+            &token_program.key(),
+            from.to_account_info(),
+            mint.to_account_info(),
+            to.to_account_info(),
+            distributor.to_account_info(),
+            &[],
+            amount,
+            mint.decimals,
+            signer_seeds,
+        )
+    })?;
+
+    Ok(transfer_fee)
+}
+
+/// Transfers from `funder` into the distributor vault with `transfer_checked`, signed by its `authority`,
+/// grossed up so the vault receives `amount` after any Token-2022 transfer fee.
+/// Returns the amount debited from the funder.
+#[allow(clippy::result_large_err)]
+pub fn transfer_to_vault<'info>(
+    funder: &InterfaceAccount<'info, TokenAccount>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    authority: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let gross_amount = amount
+        .checked_add(get_inverse_transfer_fee(&mint.to_account_info(), amount)?)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        funder.amount >= gross_amount,
+        ErrorCode::InsufficientFunderBalance
+    );
+
+    invoke_transfer_checked(
+        &token_program.key(),
+        funder.to_account_info(),
+        mint.to_account_info(),
+        vault.to_account_info(),
+        authority.to_account_info(),
+        &[],
+        gross_amount,
+        mint.decimals,
+        &[],
+    )?;
+
+    Ok(gross_amount)
+}
+
+/// Closes the empty distributor vault, signing as the [MerkleDistributor] PDA, and sends its rent to `destination`.
+/// A Token-2022 vault still holding withheld transfer fees must have them harvested first.
+#[allow(clippy::result_large_err)]
+pub fn close_vault<'info>(
+    distributor: &Account<'info, MerkleDistributor>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: &AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    with_distributor_signer(distributor, |signer_seeds| {
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: destination.clone(),
+                authority: distributor.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    })
+}
+
+/// Creates the PDA `account` with `space` bytes owned by this program, rent paid by `payer`, like Anchor's `init`.
+/// Lamports already sent to the address are kept and topped up, so they cannot block its creation.
+#[allow(clippy::result_large_err)]
+pub fn create_program_account<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let system_program = system_program.to_account_info();
+
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+/// Calls `f` with the seeds signing as the [MerkleDistributor] PDA.
+/// Distributors created before their admin was part of the seeds have a default base and keep their original seeds.
+fn with_distributor_signer<R>(
+    distributor: &MerkleDistributor,
+    f: impl FnOnce(&[&[&[u8]]]) -> R,
+) -> R {
+    let version = distributor.version.to_le_bytes();
+    let bump = [distributor.bump];
+
+    if distributor.base == Pubkey::default() {
+        let seeds: &[&[u8]] = &[
+            b"MerkleDistributor".as_ref(),
+            distributor.mint.as_ref(),
+            &version,
+            &bump,
+        ];
+        f(&[seeds])
+    } else {
+        let seeds: &[&[u8]] = &[
+            b"MerkleDistributor".as_ref(),
+            distributor.base.as_ref(),
+            distributor.mint.as_ref(),
+            &version,
+            &bump,
+        ];
+        f(&[seeds])
+    }
+}
+
+/// Returns the fee the mint withholds when transferring `amount`.
+/// SPL Token mints and Token-2022 mints without the transfer fee extension charge nothing.
+#[allow(clippy::result_large_err)]
+pub fn get_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::ArithmeticError)?,
+        Err(_) => 0,
+    };
+
+    Ok(fee)
+}
+
+/// Returns the fee the mint withholds on a transfer for the recipient to receive `amount`.
+/// SPL Token mints and Token-2022 mints without the transfer fee extension charge nothing.
+#[allow(clippy::result_large_err)]
+pub fn get_inverse_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::ArithmeticError)?,
+        Err(_) => 0,
+    };
+
+    Ok(fee)
+}
+
+/// Fails if the mint has the transfer hook extension, even without a hook program set yet,
+/// since the transfers out of the vault do not pass on the accounts a hook needs.
+#[allow(clippy::result_large_err)]
+pub fn require_no_transfer_hook(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    require!(
+        mint.get_extension::<TransferHook>().is_err(),
+        ErrorCode::TransferHookNotSupported
+    );
+
+    Ok(())
+}
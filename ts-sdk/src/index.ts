@@ -25,6 +25,7 @@ export interface EligibilityResp {
   mint: string;
   start_ts: number;
   end_ts: number;
+  claim_end_ts: number;
   proof: number[][];
   start_amount: number;
   end_amount: number;
@@ -34,6 +35,9 @@ export interface EligibilityResp {
   claimable_amount: number;
   unlocked_amount_claimed: number;
   locked_amount_withdrawn: number;
+  claim_fee_lamports: number;
+  claim_fee_amount: number;
+  claim_fee_receiver: string;
 }
 
 export interface UserNotFoundResp {
@@ -58,20 +62,40 @@ export interface MerkleDistributorResp {
   clawedBack: boolean;
   enableSlot: number;
   closable: boolean;
+  metadata: DistributorMetadataResp | null;
 }
 
+export interface DistributorMetadataResp {
+  name: string;
+  campaign_id: string;
+  uri: string;
+  content_hash: string;
+}
+
+/**
+ * Get the token program owning a mint, SPL Token or Token-2022.
+ */
+export const getMintTokenProgram = async (tokenMint: PublicKey, connection: Connection): Promise<PublicKey> => {
+  const mintAccount = await connection.getAccountInfo(tokenMint);
+  if (!mintAccount) {
+    throw new Error(`Mint ${tokenMint.toBase58()} not found`);
+  }
+  return mintAccount.owner;
+};
+
 export const getOrCreateATAInstruction = async (
   tokenMint: PublicKey,
   owner: PublicKey,
   connection: Connection,
   allowOwnerOffCurve = true,
   payer = owner,
+  tokenProgram = TOKEN_PROGRAM_ID,
 ): Promise<[PublicKey, TransactionInstruction?]> => {
   let toAccount;
   try {
     toAccount = await Token.getAssociatedTokenAddress(
       ASSOCIATED_TOKEN_PROGRAM_ID,
-      TOKEN_PROGRAM_ID,
+      tokenProgram,
       tokenMint,
       owner,
       allowOwnerOffCurve,
@@ -80,7 +104,7 @@ export const getOrCreateATAInstruction = async (
     if (!account) {
       const ix = Token.createAssociatedTokenAccountInstruction(
         ASSOCIATED_TOKEN_PROGRAM_ID,
-        TOKEN_PROGRAM_ID,
+        tokenProgram,
         tokenMint,
         toAccount,
         owner,
@@ -104,6 +128,8 @@ export interface ClaimIxConfig {
   distributorProgramId: PublicKey;
   userEligibility: EligibilityResp;
   ignoreTokenAccountCreation?: boolean;
+  /** Pays the rent of the claim status and token accounts, and any lamport claim fee. Defaults to the claimant */
+  payer?: PublicKey;
 }
 
 export interface ClaimLockedIxConfig {
@@ -168,6 +194,57 @@ export default class MerkleDistributorAPI {
     }
   }
 
+  static async getUserProofs(
+    baseUrl: string,
+    userPubkey: PublicKey,
+    authUsername?: string,
+    authPassword?: string,
+  ): Promise<UserProof[]> {
+    const url = `${baseUrl}/proofs/${userPubkey.toBase58()}`;
+    const headers = new Headers();
+    if (authUsername && authPassword) {
+      headers.set('Authorization', 'Basic ' + btoa(authUsername + ':' + authPassword));
+    }
+    const response = await fetch(url, { headers });
+    return (await response.json()) as UserProof[];
+  }
+
+  static async getClaimStatuses(
+    baseUrl: string,
+    userPubkey: PublicKey,
+    authUsername?: string,
+    authPassword?: string,
+  ): Promise<ClaimStatusResp[]> {
+    const url = `${baseUrl}/claims/${userPubkey.toBase58()}`;
+    const headers = new Headers();
+    if (authUsername && authPassword) {
+      headers.set('Authorization', 'Basic ' + btoa(authUsername + ':' + authPassword));
+    }
+    const response = await fetch(url, { headers });
+    return (await response.json()) as ClaimStatusResp[];
+  }
+
+  static async getEligibilities(
+    baseUrl: string,
+    userPubkey: PublicKey,
+    authUsername?: string,
+    authPassword?: string,
+  ): Promise<EligibilityResp[] | UserNotFoundResp> {
+    const url = `${baseUrl}/eligibilities/${userPubkey.toBase58()}`;
+    const headers = new Headers();
+    if (authUsername && authPassword) {
+      headers.set('Authorization', 'Basic ' + btoa(authUsername + ':' + authPassword));
+    }
+    const response = await fetch(url, { headers });
+    if (response.status === 200) {
+      return (await response.json()) as EligibilityResp[];
+    } else if (response.status === 404) {
+      return (await response.json()) as UserNotFoundResp;
+    } else {
+      return await response.json();
+    }
+  }
+
   static async getDistributors(
     baseUrl: string,
     authUsername?: string,
@@ -221,6 +298,8 @@ export default class MerkleDistributorAPI {
     const claimant = new PublicKey(user.claimant);
     const distributor = new PublicKey(user.merkle_tree);
     const mint = new PublicKey(user.mint);
+    const claimFeeReceiver = new PublicKey(user.claim_fee_receiver);
+    const payer = config.payer ?? claimant;
 
     const [claimStatusPubKey, _] = MerkleDistributorAPI.deriveClaimStatus(
       claimant,
@@ -230,8 +309,23 @@ export default class MerkleDistributorAPI {
 
     const ixs: TransactionInstruction[] = [];
 
-    const [toATA, toATAIx] = await getOrCreateATAInstruction(mint, claimant, provider.connection, true, claimant);
-    const [mdATA, mdATAIx] = await getOrCreateATAInstruction(mint, distributor, provider.connection, true, claimant);
+    const tokenProgram = await getMintTokenProgram(mint, provider.connection);
+    const [toATA, toATAIx] = await getOrCreateATAInstruction(
+      mint,
+      claimant,
+      provider.connection,
+      true,
+      payer,
+      tokenProgram,
+    );
+    const [mdATA, mdATAIx] = await getOrCreateATAInstruction(
+      mint,
+      distributor,
+      provider.connection,
+      true,
+      payer,
+      tokenProgram,
+    );
 
     if (toATAIx && !config.ignoreTokenAccountCreation) {
       ixs.push(toATAIx);
@@ -246,12 +340,15 @@ export default class MerkleDistributorAPI {
         .newClaim(new BN(user.end_amount), new BN(user.locked_amount), user.proof as any)
         .accounts({
           claimant,
+          payer,
           claimStatus: claimStatusPubKey,
           distributor,
           from: mdATA,
           to: toATA,
+          mint,
           systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenProgram,
+          claimFeeReceiver: claimFeeReceiver.equals(PublicKey.default) ? null : claimFeeReceiver,
         })
         .instruction(),
     ];
@@ -280,8 +377,23 @@ export default class MerkleDistributorAPI {
 
     const ixs: TransactionInstruction[] = [];
 
-    const [toATA, toATAIx] = await getOrCreateATAInstruction(mint, claimant, provider.connection, true, claimant);
-    const [mdATA, mdATAIx] = await getOrCreateATAInstruction(mint, distributor, provider.connection, true, claimant);
+    const tokenProgram = await getMintTokenProgram(mint, provider.connection);
+    const [toATA, toATAIx] = await getOrCreateATAInstruction(
+      mint,
+      claimant,
+      provider.connection,
+      true,
+      claimant,
+      tokenProgram,
+    );
+    const [mdATA, mdATAIx] = await getOrCreateATAInstruction(
+      mint,
+      distributor,
+      provider.connection,
+      true,
+      claimant,
+      tokenProgram,
+    );
 
     if (toATAIx && !config.ignoreTokenAccountCreation) {
       ixs.push(toATAIx);
@@ -300,7 +412,8 @@ export default class MerkleDistributorAPI {
           distributor, //
           from: mdATA, //
           to: toATA, //
-          tokenProgram: TOKEN_PROGRAM_ID, //
+          mint, //
+          tokenProgram, //
         })
         .instruction(),
     ];